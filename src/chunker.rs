@@ -0,0 +1,350 @@
+//! Shallow syntax over tagged words: phrase chunks and the clauses around
+//! them.
+//!
+//! Both stages work from the part-of-speech tags alone, one sentence at a
+//! time, and write `PhraseKind` spans into the document. They need a tag
+//! stage before them.
+
+use std::ops::Range;
+
+use crate::document::Document;
+use crate::error::Result;
+use crate::parser::features;
+use crate::parser::part_of_speech::PartOfSpeechKind;
+use crate::parser::tagset::PennTag;
+use crate::parser::word_enums::ConjunctionType;
+use crate::parser::word_group_tokens::PhraseKind;
+use crate::processor::{Stage, StageKind};
+use crate::tokenizer::TokenKind;
+
+/// A token of a sentence other than whitespace, with its class and Penn
+/// tag if it was tagged.
+struct SentenceWord {
+    index: usize,
+    text: String,
+    kind: Option<PartOfSpeechKind>,
+    penn: Option<PennTag>,
+}
+
+impl SentenceWord {
+    fn is(&self, kind: PartOfSpeechKind) -> bool {
+        self.kind == Some(kind)
+    }
+
+    fn is_text(&self, text: &str) -> bool {
+        self.text.eq_ignore_ascii_case(text)
+    }
+}
+
+/// The tokens of each sentence of `document`, whitespace left out.
+fn sentences(document: &Document) -> Vec<Vec<SentenceWord>> {
+    let sentence_words = |range: &Range<usize>| {
+        range
+            .clone()
+            .filter_map(|index| {
+                let token = document.token(index)?;
+                let part_of_speech = token.part_of_speech();
+                let kind = match token.token().kind() {
+                    TokenKind::Whitespace => return None,
+                    TokenKind::Punctuation => Some(PartOfSpeechKind::Punctuation),
+                    _ => part_of_speech.map(|part_of_speech| part_of_speech.kind()),
+                };
+                Some(SentenceWord {
+                    index,
+                    text: token.text().to_string(),
+                    kind,
+                    penn: part_of_speech.map(|part_of_speech| part_of_speech.tag().penn()),
+                })
+            })
+            .collect()
+    };
+    document
+        .sentence_ranges()
+        .iter()
+        .map(sentence_words)
+        .collect()
+}
+
+/// The token indices from word `start` up to word `end` of a sentence.
+fn span(words: &[SentenceWord], start: usize, end: usize) -> Range<usize> {
+    words[start].index..words[end - 1].index + 1
+}
+
+/// Groups tagged words into flat, non-recursive phrases: noun phrases
+/// such as `the fatal loins`, verb groups such as `shall strive`,
+/// prepositional phrases with the noun phrase they govern, infinitives,
+/// and runs of adjectives, adverbs and interjections. Punctuation and
+/// untagged tokens end a phrase. A prepositional phrase is added before
+/// the noun phrase inside it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Chunker;
+
+impl Chunker {
+    pub fn new() -> Chunker {
+        Chunker
+    }
+
+    /// The phrases of one sentence, as kinds and ranges of word positions.
+    fn chunk(&self, words: &[SentenceWord]) -> Vec<(PhraseKind, usize, usize)> {
+        let mut phrases = Vec::new();
+        let mut position = 0;
+        while position < words.len() {
+            let word = &words[position];
+            if let Some(end) = noun_phrase(words, position) {
+                phrases.push((PhraseKind::NounPhrase, position, end));
+                position = end;
+            } else if let Some(end) = verb_group(words, position) {
+                phrases.push((PhraseKind::VerbPhrase, position, end));
+                position = end;
+            } else if let Some(end) = word
+                .is(PartOfSpeechKind::Preposition)
+                .then(|| noun_phrase(words, position + 1))
+                .flatten()
+            {
+                phrases.push((PhraseKind::PrepositionalPhrase, position, end));
+                phrases.push((PhraseKind::NounPhrase, position + 1, end));
+                position = end;
+            } else if let Some(end) = (word.is(PartOfSpeechKind::Particle) && word.is_text("to"))
+                .then(|| verb_group(words, position + 1))
+                .flatten()
+            {
+                phrases.push((PhraseKind::InfinitivePhrase, position, end));
+                position = end;
+            } else if word.is(PartOfSpeechKind::Adverb) || word.is(PartOfSpeechKind::Adjective) {
+                let adverbs = run(words, position, &[PartOfSpeechKind::Adverb]);
+                let end = run(words, adverbs, &[PartOfSpeechKind::Adjective]);
+                let kind = match end > adverbs {
+                    true => PhraseKind::AdjectivePhrase,
+                    false => PhraseKind::AdverbPhrase,
+                };
+                phrases.push((kind, position, end));
+                position = end;
+            } else if word.is(PartOfSpeechKind::Interjection) {
+                phrases.push((PhraseKind::InterjectionPhrase, position, position + 1));
+                position += 1;
+            } else {
+                position += 1;
+            }
+        }
+        phrases
+    }
+}
+
+impl Stage for Chunker {
+    fn kind(&self) -> StageKind {
+        StageKind::Chunk
+    }
+
+    fn run(&self, document: &mut Document) -> Result<()> {
+        for words in sentences(document) {
+            for (kind, start, end) in self.chunk(&words) {
+                document.add_phrase(kind, span(&words, start, end));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The position after the words of `kinds` starting at `start`.
+fn run(words: &[SentenceWord], start: usize, kinds: &[PartOfSpeechKind]) -> usize {
+    let length = words[start..]
+        .iter()
+        .take_while(|word| word.kind.is_some_and(|kind| kinds.contains(&kind)))
+        .count();
+    start + length
+}
+
+/// The end of a noun phrase starting at `start`: a pronoun on its own, or
+/// an article, determiner or possessive, numerals and adjectives, then
+/// nouns. Adjectives need something before or after them, as in `the
+/// weakest`, or they are left to an adjective phrase.
+fn noun_phrase(words: &[SentenceWord], start: usize) -> Option<usize> {
+    let first = words.get(start)?;
+    let possessive = matches!(
+        first.penn,
+        Some(PennTag::PossessivePronoun | PennTag::PossessiveWhPronoun)
+    );
+    if first.is(PartOfSpeechKind::Pronoun) && !possessive {
+        return Some(start + 1);
+    }
+    let specifier =
+        possessive || first.is(PartOfSpeechKind::Article) || first.is(PartOfSpeechKind::Determiner);
+    let numerals = run(
+        words,
+        start + usize::from(specifier),
+        &[PartOfSpeechKind::Numeral],
+    );
+    let adjectives = run(words, numerals, &[PartOfSpeechKind::Adjective]);
+    let nouns = run(words, adjectives, &[PartOfSpeechKind::Noun]);
+    let specified = numerals > start;
+    (nouns > adjectives || specified).then_some(nouns)
+}
+
+/// The end of a verb group starting at `start`: modals, auxiliaries and
+/// verbs, with any adverbs and `not` between them, as in `art not quickly
+/// moved`. It ends on its last verb.
+fn verb_group(words: &[SentenceWord], start: usize) -> Option<usize> {
+    let is_verb = |word: &SentenceWord| {
+        word.is(PartOfSpeechKind::Verb)
+            || word.is(PartOfSpeechKind::Auxiliary)
+            || word.is(PartOfSpeechKind::Modal)
+    };
+    let is_inside = |word: &SentenceWord| {
+        word.is(PartOfSpeechKind::Adverb)
+            || word.is(PartOfSpeechKind::Particle) && !word.is_text("to")
+    };
+    if !is_verb(words.get(start)?) {
+        return None;
+    }
+    let mut end = start + 1;
+    let mut position = end;
+    while let Some(word) = words.get(position) {
+        if is_verb(word) {
+            end = position + 1;
+        } else if !is_inside(word) {
+            break;
+        }
+        position += 1;
+    }
+    Some(end)
+}
+
+/// Marks each sentence and the dependent clauses in it: adverb clauses
+/// opened by a subordinating conjunction, as in `if thou art moved`, and
+/// relative clauses opened by a wh- pronoun or determiner after the start
+/// of the sentence. A dependent clause runs to the next punctuation mark
+/// or the end of the sentence. This is a shallow parse; it does not find
+/// clauses that are not marked by their first word.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClauseParser;
+
+impl ClauseParser {
+    pub fn new() -> ClauseParser {
+        ClauseParser
+    }
+
+    /// The clauses of one sentence, as kinds and ranges of word positions.
+    fn parse(&self, words: &[SentenceWord]) -> Vec<(PhraseKind, usize, usize)> {
+        let mut clauses = vec![(PhraseKind::Sentence, 0, words.len())];
+        for (position, word) in words.iter().enumerate() {
+            let subordinating = word.is(PartOfSpeechKind::Conjunction)
+                && features::conjunction_features(&word.text).conjunction_type
+                    == ConjunctionType::Subordinating;
+            let relative = position > 0
+                && matches!(
+                    word.penn,
+                    Some(PennTag::WhPronoun | PennTag::WhDeterminer | PennTag::PossessiveWhPronoun)
+                );
+            let kind = match (subordinating, relative) {
+                (true, _) => PhraseKind::AdverbClause,
+                (_, true) => PhraseKind::RelativeClause,
+                _ => continue,
+            };
+            let end = words[position + 1..]
+                .iter()
+                .position(|word| word.is(PartOfSpeechKind::Punctuation))
+                .map_or(words.len(), |offset| position + 1 + offset);
+            if end > position + 1 {
+                clauses.push((kind, position, end));
+            }
+        }
+        clauses
+    }
+}
+
+impl Stage for ClauseParser {
+    fn kind(&self) -> StageKind {
+        StageKind::Parse
+    }
+
+    fn run(&self, document: &mut Document) -> Result<()> {
+        for words in sentences(document).iter().filter(|words| !words.is_empty()) {
+            for (kind, start, end) in self.parse(words) {
+                document.add_phrase(kind, span(words, start, end));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::Processor;
+    use crate::tagger::LexiconTagger;
+    use crate::{Preprocessor, PreprocessorOptions};
+
+    fn phrases(text: &str, stage: impl Stage + 'static) -> Vec<(PhraseKind, String)> {
+        let preprocessor = Preprocessor::from_text(text, PreprocessorOptions::default()).unwrap();
+        let mut processor = Processor::new();
+        processor.add_stage(LexiconTagger::new());
+        processor.add_stage(stage);
+        let document = processor.process(&preprocessor).unwrap();
+        document
+            .phrases()
+            .iter()
+            .map(|phrase| {
+                let text = document.text_of(&phrase.token_range());
+                (phrase.kind(), text.to_string())
+            })
+            .collect()
+    }
+
+    fn expected(phrases: &[(PhraseKind, &str)]) -> Vec<(PhraseKind, String)> {
+        phrases
+            .iter()
+            .map(|&(kind, text)| (kind, text.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn chunks_tagged_words() {
+        assert_eq!(
+            phrases("A dog of the house of Montague moves me.", Chunker::new()),
+            expected(&[
+                (PhraseKind::NounPhrase, "A dog"),
+                (PhraseKind::PrepositionalPhrase, "of the house"),
+                (PhraseKind::NounPhrase, "the house"),
+                (PhraseKind::PrepositionalPhrase, "of Montague"),
+                (PhraseKind::NounPhrase, "Montague"),
+                (PhraseKind::VerbPhrase, "moves"),
+                (PhraseKind::NounPhrase, "me"),
+            ])
+        );
+        assert_eq!(
+            phrases("But thou art not quickly moved to strike.", Chunker::new()),
+            expected(&[
+                (PhraseKind::NounPhrase, "thou"),
+                (PhraseKind::VerbPhrase, "art not quickly moved"),
+                (PhraseKind::InfinitivePhrase, "to strike"),
+            ])
+        );
+        assert_eq!(
+            phrases("Ay, the weakest goes very quickly.", Chunker::new()),
+            expected(&[
+                (PhraseKind::InterjectionPhrase, "Ay"),
+                (PhraseKind::NounPhrase, "the weakest"),
+                (PhraseKind::VerbPhrase, "goes"),
+                (PhraseKind::AdverbPhrase, "very quickly"),
+            ])
+        );
+    }
+
+    #[test]
+    fn finds_dependent_clauses() {
+        let text = "I will show myself a tyrant, when I have fought with the men.";
+        assert_eq!(
+            phrases(text, ClauseParser::new()),
+            expected(&[
+                (PhraseKind::Sentence, text),
+                (PhraseKind::AdverbClause, "when I have fought with the men"),
+            ])
+        );
+        let text = "The quarrel is between our masters, who are men.";
+        assert_eq!(
+            phrases(text, ClauseParser::new())[1],
+            (PhraseKind::RelativeClause, "who are men".to_string())
+        );
+        assert_eq!(phrases("Who goes there?", ClauseParser::new()).len(), 1);
+    }
+}
@@ -0,0 +1,572 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+
+use langcumen::error::Result;
+use langcumen::parser::part_of_speech::PartOfSpeech;
+use langcumen::sentences::punkt::{PunktParameters, PunktSplitter};
+use langcumen::sentences::SentenceStrategy;
+use langcumen::stream::{SentenceStream, StreamedSentence, TokenStream};
+use langcumen::tagger::LexiconTagger;
+use langcumen::tokenizer::{Token, Tokenizer, TokenizerMode};
+use langcumen::vocabulary::Vocabulary;
+
+pub const USAGE: &str = "\
+Usage: langcumen <command> [options] [path ...]
+
+Reads each path in turn, or standard input if none is given or the path is `-`.
+
+Commands:
+  tokenize     one token per line
+  sentences    one sentence per line
+  vocab        word types with their frequencies, most frequent first
+  tag          every sentence as word/TAG pairs of part-of-speech tags
+  stats        token, type and sentence counts
+
+Options:
+  -f, --format <text|json|tsv>  output format (default: text; json is one object per line)
+      --plain                   split apostrophes off word edges instead of keeping elisions
+      --expand                  expand contractions such as 'tis into it is
+      --whitespace              include whitespace tokens when tokenizing
+      --punkt <path>            split sentences with trained Punkt parameters
+      --tagset <native|ud|penn> tags to write (default: native)
+      --top <n>                 only list the n most frequent words
+      --limit <n>               keep at most n word types in memory while counting
+  -h, --help                    show this message
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Tokenize,
+    Sentences,
+    Vocab,
+    Tag,
+    Stats,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Tsv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tagset {
+    Native,
+    Universal,
+    Penn,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    command: Command,
+    format: Format,
+    inputs: Vec<String>,
+    plain: bool,
+    expand: bool,
+    whitespace: bool,
+    punkt: Option<PathBuf>,
+    tagset: Tagset,
+    top: Option<usize>,
+    limit: Option<usize>,
+}
+
+/// Parses the arguments after the program name. `Ok(None)` means help was
+/// asked for; `Err` carries a message for the user.
+pub fn parse_args(
+    args: impl IntoIterator<Item = String>,
+) -> std::result::Result<Option<Options>, String> {
+    let mut args = args.into_iter();
+    let command = match args.next().as_deref() {
+        Some("tokenize") => Command::Tokenize,
+        Some("sentences") => Command::Sentences,
+        Some("vocab") => Command::Vocab,
+        Some("tag") => Command::Tag,
+        Some("stats") => Command::Stats,
+        Some("-h" | "--help") | None => return Ok(None),
+        Some(other) => return Err(format!("unknown command `{}`", other)),
+    };
+
+    let mut options = Options {
+        command,
+        format: Format::Text,
+        inputs: Vec::new(),
+        plain: false,
+        expand: false,
+        whitespace: false,
+        punkt: None,
+        tagset: Tagset::Native,
+        top: None,
+        limit: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-f" | "--format" => {
+                options.format = match value(&mut args, &arg)?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    "tsv" => Format::Tsv,
+                    other => return Err(format!("unknown format `{}`", other)),
+                }
+            }
+            "--plain" => options.plain = true,
+            "--expand" => options.expand = true,
+            "--whitespace" => options.whitespace = true,
+            "--punkt" => options.punkt = Some(PathBuf::from(value(&mut args, &arg)?)),
+            "--tagset" => {
+                options.tagset = match value(&mut args, &arg)?.as_str() {
+                    "native" => Tagset::Native,
+                    "ud" => Tagset::Universal,
+                    "penn" => Tagset::Penn,
+                    other => return Err(format!("unknown tagset `{}`", other)),
+                }
+            }
+            "--top" => options.top = Some(number(&mut args, &arg)?),
+            "--limit" => options.limit = Some(number(&mut args, &arg)?),
+            "-" => options.inputs.push(arg),
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{}`", arg)),
+            _ => options.inputs.push(arg),
+        }
+    }
+    Ok(Some(options))
+}
+
+fn value(
+    args: &mut impl Iterator<Item = String>,
+    option: &str,
+) -> std::result::Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("`{}` needs a value", option))
+}
+
+fn number(
+    args: &mut impl Iterator<Item = String>,
+    option: &str,
+) -> std::result::Result<usize, String> {
+    let value = value(args, option)?;
+    value
+        .parse()
+        .map_err(|_| format!("`{}` needs a number, not `{}`", option, value))
+}
+
+pub fn run(options: &Options, output: &mut impl Write) -> Result<()> {
+    let mode = match options.plain {
+        true => TokenizerMode::Plain,
+        false => TokenizerMode::Contractions,
+    };
+    let tokenizer = Tokenizer::new(mode);
+    let tagger = LexiconTagger::new();
+    let strategy = match &options.punkt {
+        Some(path) => SentenceStrategy::Punkt(PunktSplitter::new(PunktParameters::load(path)?)),
+        None => SentenceStrategy::default(),
+    };
+    let inputs = match options.inputs.is_empty() {
+        true => vec!["-".to_string()],
+        false => options.inputs.clone(),
+    };
+
+    let mut vocabulary = match options.limit {
+        Some(limit) => Vocabulary::with_limit(limit),
+        None => Vocabulary::new(),
+    };
+    let mut sentence_count = 0;
+
+    for source in &inputs {
+        let reader = open(source)?;
+        let tokens = TokenStream::with_vocabulary(reader, tokenizer.clone(), vocabulary);
+
+        if options.command == Command::Tokenize {
+            let mut tokens = tokens;
+            for token in tokens.by_ref() {
+                for token in expand(&tokenizer, &token?, options.expand) {
+                    if options.whitespace || !token.is_whitespace() {
+                        write_token(output, options.format, source, &token)?;
+                    }
+                }
+            }
+            vocabulary = tokens.into_vocabulary();
+            continue;
+        }
+
+        let mut sentences = SentenceStream::new(tokens, strategy.clone());
+        for sentence in sentences.by_ref() {
+            let sentence = sentence?;
+            sentence_count += 1;
+            match options.command {
+                Command::Sentences => write_sentence(output, options.format, source, &sentence)?,
+                Command::Tag => {
+                    let tokens: VecDeque<Token> = sentence
+                        .tokens()
+                        .iter()
+                        .flat_map(|token| expand(&tokenizer, token, options.expand))
+                        .collect();
+                    let tagged: Vec<(Token, &str)> = tokens
+                        .iter()
+                        .zip(tagger.tag(&tokens))
+                        .filter(|(token, _)| !token.is_whitespace())
+                        .map(|(token, tag)| (token.clone(), label(tag.as_ref(), options.tagset)))
+                        .collect();
+                    write_tagged(output, options.format, source, &tagged)?;
+                }
+                _ => {}
+            }
+        }
+        vocabulary = sentences.into_vocabulary();
+    }
+
+    match options.command {
+        Command::Vocab => write_vocabulary(output, options.format, &vocabulary, options.top),
+        Command::Stats => write_stats(
+            output,
+            options.format,
+            &vocabulary,
+            inputs.len(),
+            sentence_count,
+        ),
+        _ => Ok(()),
+    }
+}
+
+fn open(source: &str) -> Result<Box<dyn BufRead>> {
+    match source {
+        "-" => Ok(Box::new(BufReader::new(io::stdin()))),
+        path => Ok(Box::new(BufReader::new(File::open(path)?))),
+    }
+}
+
+fn expand(tokenizer: &Tokenizer, token: &Token, expand: bool) -> Vec<Token> {
+    match expand {
+        true => tokenizer.expand_token(token),
+        false => vec![token.clone()],
+    }
+}
+
+/// The label of a tagged token in `tagset`; untagged tokens are `X`.
+fn label(tag: Option<&PartOfSpeech>, tagset: Tagset) -> &'static str {
+    match (tag, tagset) {
+        (Some(tag), Tagset::Native) => tag.label(),
+        (Some(tag), Tagset::Universal) => tag.tag().universal().label(),
+        (Some(tag), Tagset::Penn) => tag.tag().penn().label(),
+        (None, _) => "X",
+    }
+}
+
+fn write_token(output: &mut impl Write, format: Format, source: &str, token: &Token) -> Result<()> {
+    match format {
+        Format::Text => writeln!(output, "{}", escape_tsv(token.text()))?,
+        Format::Tsv => writeln!(
+            output,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            escape_tsv(source),
+            token.start(),
+            token.end(),
+            token.line(),
+            token.column(),
+            token.kind().name(),
+            escape_tsv(token.text())
+        )?,
+        Format::Json => writeln!(
+            output,
+            "{{\"source\":{},\"start\":{},\"end\":{},\"line\":{},\"column\":{},\"kind\":\"{}\",\"text\":{}}}",
+            json_string(source),
+            token.start(),
+            token.end(),
+            token.line(),
+            token.column(),
+            token.kind().name(),
+            json_string(token.text())
+        )?,
+    }
+    Ok(())
+}
+
+fn write_sentence(
+    output: &mut impl Write,
+    format: Format,
+    source: &str,
+    sentence: &StreamedSentence,
+) -> Result<()> {
+    let text = sentence.text();
+    let span = sentence.span();
+    match format {
+        Format::Text => writeln!(output, "{}", collapse_whitespace(&text))?,
+        Format::Tsv => writeln!(
+            output,
+            "{}\t{}\t{}\t{}",
+            escape_tsv(source),
+            span.start,
+            span.end,
+            escape_tsv(&text)
+        )?,
+        Format::Json => writeln!(
+            output,
+            "{{\"source\":{},\"start\":{},\"end\":{},\"text\":{}}}",
+            json_string(source),
+            span.start,
+            span.end,
+            json_string(&text)
+        )?,
+    }
+    Ok(())
+}
+
+fn write_tagged(
+    output: &mut impl Write,
+    format: Format,
+    source: &str,
+    tokens: &[(Token, &str)],
+) -> Result<()> {
+    match format {
+        Format::Text => {
+            let pairs: Vec<String> = tokens
+                .iter()
+                .map(|(token, tag)| format!("{}/{}", token.text(), tag))
+                .collect();
+            writeln!(output, "{}", pairs.join(" "))?;
+        }
+        Format::Tsv => {
+            for (token, tag) in tokens {
+                writeln!(
+                    output,
+                    "{}\t{}\t{}\t{}\t{}",
+                    escape_tsv(source),
+                    token.start(),
+                    token.end(),
+                    escape_tsv(token.text()),
+                    tag
+                )?;
+            }
+            writeln!(output)?;
+        }
+        Format::Json => {
+            let pairs: Vec<String> = tokens
+                .iter()
+                .map(|(token, tag)| {
+                    format!(
+                        "{{\"start\":{},\"end\":{},\"text\":{},\"tag\":\"{}\"}}",
+                        token.start(),
+                        token.end(),
+                        json_string(token.text()),
+                        tag
+                    )
+                })
+                .collect();
+            writeln!(
+                output,
+                "{{\"source\":{},\"tokens\":[{}]}}",
+                json_string(source),
+                pairs.join(",")
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn write_vocabulary(
+    output: &mut impl Write,
+    format: Format,
+    vocabulary: &Vocabulary,
+    top: Option<usize>,
+) -> Result<()> {
+    for entry in vocabulary.top_n(top.unwrap_or(vocabulary.len())) {
+        match format {
+            Format::Text => writeln!(output, "{:>8} {}", entry.frequency(), entry.word())?,
+            Format::Tsv => writeln!(
+                output,
+                "{}\t{}\t{}\t{}\t{}",
+                entry.id(),
+                escape_tsv(entry.word()),
+                entry.frequency(),
+                entry.document_frequency(),
+                entry.first_offset()
+            )?,
+            Format::Json => writeln!(
+                output,
+                "{{\"id\":{},\"word\":{},\"frequency\":{},\"document_frequency\":{},\"first_offset\":{}}}",
+                entry.id(),
+                json_string(entry.word()),
+                entry.frequency(),
+                entry.document_frequency(),
+                entry.first_offset()
+            )?,
+        }
+    }
+    Ok(())
+}
+
+fn write_stats(
+    output: &mut impl Write,
+    format: Format,
+    vocabulary: &Vocabulary,
+    documents: usize,
+    sentences: usize,
+) -> Result<()> {
+    let words_per_sentence = match sentences {
+        0 => 0.0,
+        sentences => vocabulary.token_count() as f64 / sentences as f64,
+    };
+    let stats = [
+        ("documents", documents.to_string()),
+        ("sentences", sentences.to_string()),
+        ("words", vocabulary.token_count().to_string()),
+        ("types", vocabulary.len().to_string()),
+        (
+            "hapax_legomena",
+            vocabulary.hapax_legomena().len().to_string(),
+        ),
+        (
+            "type_token_ratio",
+            format!("{:.4}", vocabulary.type_token_ratio()),
+        ),
+        ("words_per_sentence", format!("{:.2}", words_per_sentence)),
+    ];
+
+    match format {
+        Format::Text => {
+            for (name, value) in &stats {
+                writeln!(output, "{}: {}", name, value)?;
+            }
+        }
+        Format::Tsv => {
+            for (name, value) in &stats {
+                writeln!(output, "{}\t{}", name, value)?;
+            }
+        }
+        Format::Json => {
+            let fields: Vec<String> = stats
+                .iter()
+                .map(|(name, value)| format!("\"{}\":{}", name, value))
+                .collect();
+            writeln!(output, "{{{}}}", fields.join(","))?;
+        }
+    }
+    Ok(())
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+fn escape_tsv(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn json_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> std::result::Result<Option<Options>, String> {
+        parse_args(line.split_whitespace().map(String::from))
+    }
+
+    fn run_on(line: &str) -> String {
+        let options = args(line).unwrap().unwrap();
+        let mut output = Vec::new();
+        run(&options, &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn parses_commands_and_options() {
+        let options = args("vocab --format tsv --top 5 a.txt - b.txt")
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.command, Command::Vocab);
+        assert_eq!(options.format, Format::Tsv);
+        assert_eq!(options.top, Some(5));
+        assert_eq!(options.inputs, ["a.txt", "-", "b.txt"]);
+        assert_eq!(args("tag -h"), Ok(None));
+        assert_eq!(args(""), Ok(None));
+    }
+
+    #[test]
+    fn rejects_bad_arguments() {
+        assert!(args("parse").is_err());
+        assert!(args("tokenize --format xml").is_err());
+        assert!(args("vocab --top").is_err());
+        assert!(args("vocab --top many").is_err());
+        assert!(args("stats --verbose").is_err());
+        assert!(args("tag --tagset brown").is_err());
+    }
+
+    #[test]
+    fn accepts_every_documented_option() {
+        let options = USAGE
+            .split_whitespace()
+            .filter(|word| word.starts_with('-') && word.len() > 1)
+            .map(|word| word.trim_end_matches(','));
+        for option in options {
+            let result = args(&format!("stats {}", option));
+            assert!(
+                !result.is_err_and(|message| message.starts_with("unknown")),
+                "{} is documented but not accepted",
+                option
+            );
+        }
+    }
+
+    #[test]
+    fn lists_the_most_frequent_words() {
+        let output = run_on("vocab --top 3 romeo_and_juliet.txt");
+        assert_eq!(output.lines().count(), 3);
+        assert!(output.lines().next().unwrap().ends_with(" and"));
+    }
+
+    #[test]
+    fn counts_across_files() {
+        let output = run_on("stats -f json romeo_and_juliet.txt romeo_and_juliet.txt");
+        assert!(output.starts_with("{\"documents\":2,"));
+    }
+
+    #[test]
+    fn writes_standard_tagsets() {
+        let input =
+            std::env::temp_dir().join(format!("langcumen-tagset-{}.txt", std::process::id()));
+        std::fs::write(&input, "Two households, both alike.\n").unwrap();
+        let path = input.display();
+        assert_eq!(
+            run_on(&format!("tag {}", path)),
+            "Two/NUM households/NOUN ,/PUNCT both/DET alike/ADJ ./PUNCT\n"
+        );
+        assert_eq!(
+            run_on(&format!("tag --tagset penn {}", path)),
+            "Two/CD households/NNS ,/, both/DT alike/JJ ./.\n"
+        );
+        assert_eq!(
+            run_on(&format!("tag --tagset ud {}", path)),
+            "Two/NUM households/NOUN ,/PUNCT both/DET alike/ADJ ./PUNCT\n"
+        );
+        std::fs::remove_file(&input).unwrap();
+    }
+
+    #[test]
+    fn escapes_json_strings() {
+        assert_eq!(json_string("a \"b\"\n\u{1}"), "\"a \\\"b\\\"\\n\\u0001\"");
+        assert_eq!(escape_tsv("a\tb\\"), "a\\tb\\\\");
+    }
+}
@@ -0,0 +1,469 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::{self, Display};
+use std::ops::Range;
+
+use crate::parser::part_of_speech::PartOfSpeech;
+use crate::parser::word_group_tokens::PhraseKind;
+use crate::preprocessor::Preprocessor;
+use crate::tokenizer::Token;
+
+/// Morphological features of a token as name/value pairs, e.g.
+/// `Number=Plur`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Morphology {
+    features: BTreeMap<String, String>,
+}
+
+impl Morphology {
+    pub fn new() -> Morphology {
+        Morphology::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.features.get(name).map(String::as_str)
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.features.insert(name.to_string(), value.to_string());
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        self.features.remove(name)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// Features sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.features
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+/// Writes the features in CoNLL-U style, `Number=Plur|Person=3`, or `_`
+/// when there are none.
+impl Display for Morphology {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.features.is_empty() {
+            return write!(f, "_");
+        }
+        let features: Vec<String> = self
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        write!(f, "{}", features.join("|"))
+    }
+}
+
+/// The layers attached to a single token.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Annotation {
+    lemma: Option<String>,
+    stem: Option<String>,
+    part_of_speech: Option<PartOfSpeech>,
+    confidence: Option<f64>,
+    morphology: Morphology,
+}
+
+impl Annotation {
+    pub fn lemma(&self) -> Option<&str> {
+        self.lemma.as_deref()
+    }
+
+    pub fn set_lemma(&mut self, lemma: &str) {
+        self.lemma = Some(lemma.to_string());
+    }
+
+    pub fn stem(&self) -> Option<&str> {
+        self.stem.as_deref()
+    }
+
+    pub fn set_stem(&mut self, stem: &str) {
+        self.stem = Some(stem.to_string());
+    }
+
+    pub fn part_of_speech(&self) -> Option<&PartOfSpeech> {
+        self.part_of_speech.as_ref()
+    }
+
+    pub fn set_part_of_speech(&mut self, part_of_speech: PartOfSpeech) {
+        self.part_of_speech = Some(part_of_speech);
+    }
+
+    /// How sure the tagger was of the part of speech, from 0 to 1, if it
+    /// says.
+    pub fn confidence(&self) -> Option<f64> {
+        self.confidence
+    }
+
+    pub fn set_confidence(&mut self, confidence: f64) {
+        self.confidence = Some(confidence);
+    }
+
+    pub fn morphology(&self) -> &Morphology {
+        &self.morphology
+    }
+
+    pub fn morphology_mut(&mut self) -> &mut Morphology {
+        &mut self.morphology
+    }
+}
+
+/// A group of tokens such as a noun phrase or a relative clause.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Phrase {
+    kind: PhraseKind,
+    tokens: Range<usize>,
+}
+
+impl Phrase {
+    pub fn new(kind: PhraseKind, tokens: Range<usize>) -> Phrase {
+        Phrase { kind, tokens }
+    }
+
+    pub fn kind(&self) -> PhraseKind {
+        self.kind
+    }
+
+    /// The token indices the phrase covers.
+    pub fn token_range(&self) -> Range<usize> {
+        self.tokens.clone()
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.tokens.contains(&index)
+    }
+}
+
+/// The output of a `Processor`: text, its tokens and the layers built on
+/// top of them.
+///
+/// Every token has an `Annotation`; sentences and phrases refer to tokens
+/// by index, so replacing the tokens clears every other layer.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Document {
+    text: String,
+    tokens: VecDeque<Token>,
+    annotations: Vec<Annotation>,
+    sentences: Vec<Range<usize>>,
+    phrases: Vec<Phrase>,
+}
+
+impl Document {
+    pub fn new(text: &str) -> Document {
+        Document {
+            text: text.to_string(),
+            ..Document::default()
+        }
+    }
+
+    /// A document with the text, tokens and sentences of `preprocessor`.
+    pub fn from_preprocessor(preprocessor: &Preprocessor) -> Document {
+        let mut document = Document::new(preprocessor.original_text());
+        document.set_tokens(preprocessor.tokens().clone());
+        document.set_sentence_spans(preprocessor.sentence_tokens().iter().cloned());
+        document
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replaces the text. The tokens still point into the old text until
+    /// they are replaced too.
+    pub fn set_text(&mut self, text: String) {
+        self.text = text;
+    }
+
+    pub fn tokens(&self) -> &VecDeque<Token> {
+        &self.tokens
+    }
+
+    pub fn set_tokens(&mut self, tokens: VecDeque<Token>) {
+        self.annotations = vec![Annotation::default(); tokens.len()];
+        self.tokens = tokens;
+        self.sentences.clear();
+        self.phrases.clear();
+    }
+
+    pub fn token(&self, index: usize) -> Option<AnnotatedToken<'_>> {
+        Some(AnnotatedToken {
+            index,
+            token: self.tokens.get(index)?,
+            annotation: &self.annotations[index],
+        })
+    }
+
+    /// Every token with its annotation, whitespace included.
+    pub fn annotated_tokens(&self) -> impl Iterator<Item = AnnotatedToken<'_>> {
+        (0..self.tokens.len()).filter_map(|index| self.token(index))
+    }
+
+    /// The word tokens with their annotations.
+    pub fn words(&self) -> impl Iterator<Item = AnnotatedToken<'_>> {
+        self.annotated_tokens()
+            .filter(|token| token.token().is_word())
+    }
+
+    pub fn annotation(&self, index: usize) -> Option<&Annotation> {
+        self.annotations.get(index)
+    }
+
+    pub fn annotation_mut(&mut self, index: usize) -> Option<&mut Annotation> {
+        self.annotations.get_mut(index)
+    }
+
+    /// The index of the token covering byte `offset` of the text.
+    pub fn token_at(&self, offset: usize) -> Option<usize> {
+        let index = self.tokens.partition_point(|token| token.end() <= offset);
+        let token = self.tokens.get(index)?;
+        (token.start() <= offset).then_some(index)
+    }
+
+    /// Sentences as ranges of token indices.
+    pub fn sentence_ranges(&self) -> &[Range<usize>] {
+        &self.sentences
+    }
+
+    pub fn sentences(&self) -> impl Iterator<Item = Sentence<'_>> {
+        (0..self.sentences.len()).filter_map(|index| self.sentence(index))
+    }
+
+    pub fn sentence(&self, index: usize) -> Option<Sentence<'_>> {
+        Some(Sentence {
+            document: self,
+            index,
+            tokens: self.sentences.get(index)?.clone(),
+        })
+    }
+
+    /// The index of the sentence containing token `index`.
+    pub fn sentence_of(&self, index: usize) -> Option<usize> {
+        let sentence = self
+            .sentences
+            .partition_point(|sentence| sentence.end <= index);
+        let range = self.sentences.get(sentence)?;
+        range.contains(&index).then_some(sentence)
+    }
+
+    pub fn set_sentences(&mut self, sentences: Vec<Range<usize>>) {
+        self.sentences = sentences;
+    }
+
+    /// Sets the sentences from byte ranges into the text, such as those a
+    /// `SentenceStrategy` returns.
+    pub fn set_sentence_spans(&mut self, spans: impl IntoIterator<Item = Range<usize>>) {
+        let mut sentences = Vec::new();
+        let mut index = 0;
+        for span in spans {
+            while index < self.tokens.len() && self.tokens[index].start() < span.start {
+                index += 1;
+            }
+            let start = index;
+            while index < self.tokens.len() && self.tokens[index].end() <= span.end {
+                index += 1;
+            }
+            sentences.push(start..index);
+        }
+        self.sentences = sentences;
+    }
+
+    /// Phrases in the order they were added.
+    pub fn phrases(&self) -> &[Phrase] {
+        &self.phrases
+    }
+
+    /// The phrases that cover token `index`, outermost first if they were
+    /// added in that order.
+    pub fn phrases_at(&self, index: usize) -> impl Iterator<Item = &Phrase> {
+        self.phrases
+            .iter()
+            .filter(move |phrase| phrase.contains(index))
+    }
+
+    /// Adds a phrase over the token indices in `tokens`, which must lie
+    /// within the document.
+    pub fn add_phrase(&mut self, kind: PhraseKind, tokens: Range<usize>) {
+        debug_assert!(tokens.end <= self.tokens.len());
+        self.phrases.push(Phrase::new(kind, tokens));
+    }
+
+    /// The text covered by the tokens in `range`.
+    pub fn text_of(&self, range: &Range<usize>) -> &str {
+        match (self.tokens.get(range.start), range.end.checked_sub(1)) {
+            (Some(first), Some(last)) if range.start <= last => {
+                &self.text[first.start()..self.tokens[last].end()]
+            }
+            _ => "",
+        }
+    }
+}
+
+/// A token together with its position and annotation in a `Document`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnnotatedToken<'a> {
+    index: usize,
+    token: &'a Token,
+    annotation: &'a Annotation,
+}
+
+impl<'a> AnnotatedToken<'a> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn token(&self) -> &'a Token {
+        self.token
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.token.text()
+    }
+
+    pub fn annotation(&self) -> &'a Annotation {
+        self.annotation
+    }
+
+    pub fn lemma(&self) -> Option<&'a str> {
+        self.annotation.lemma()
+    }
+
+    pub fn stem(&self) -> Option<&'a str> {
+        self.annotation.stem()
+    }
+
+    pub fn part_of_speech(&self) -> Option<&'a PartOfSpeech> {
+        self.annotation.part_of_speech()
+    }
+
+    pub fn morphology(&self) -> &'a Morphology {
+        self.annotation.morphology()
+    }
+}
+
+/// One sentence of a `Document`.
+#[derive(Debug, Clone)]
+pub struct Sentence<'a> {
+    document: &'a Document,
+    index: usize,
+    tokens: Range<usize>,
+}
+
+impl<'a> Sentence<'a> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The token indices the sentence covers.
+    pub fn token_range(&self) -> Range<usize> {
+        self.tokens.clone()
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.document.text_of(&self.tokens)
+    }
+
+    /// The sentence's tokens, whitespace included.
+    pub fn tokens(&self) -> impl Iterator<Item = AnnotatedToken<'a>> {
+        let document = self.document;
+        self.tokens
+            .clone()
+            .filter_map(move |index| document.token(index))
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = AnnotatedToken<'a>> {
+        self.tokens().filter(|token| token.token().is_word())
+    }
+
+    /// The phrases that lie entirely within the sentence.
+    pub fn phrases(&self) -> impl Iterator<Item = &'a Phrase> {
+        let tokens = self.tokens.clone();
+        self.document.phrases.iter().filter(move |phrase| {
+            tokens.start <= phrase.tokens.start && phrase.tokens.end <= tokens.end
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::part_of_speech::{Noun, Verb};
+    use crate::preprocessor::PreprocessorOptions;
+
+    fn document(text: &str) -> Document {
+        let preprocessor = Preprocessor::from_text(text, PreprocessorOptions::default()).unwrap();
+        Document::from_preprocessor(&preprocessor)
+    }
+
+    #[test]
+    fn groups_tokens_into_sentences() {
+        let document = document("Two households. Both alike.");
+        let sentences: Vec<&str> = document
+            .sentences()
+            .map(|sentence| sentence.text())
+            .collect();
+        assert_eq!(sentences, ["Two households.", "Both alike."]);
+
+        let second = document.sentence(1).unwrap();
+        let words: Vec<&str> = second.words().map(|word| word.text()).collect();
+        assert_eq!(words, ["Both", "alike"]);
+        assert_eq!(document.sentence_of(second.token_range().start), Some(1));
+        assert_eq!(document.sentence_of(4), None);
+    }
+
+    #[test]
+    fn looks_up_tokens_by_offset() {
+        let document = document("Two households.");
+        assert_eq!(document.token_at(0), Some(0));
+        assert_eq!(document.token_at(3), Some(1));
+        assert_eq!(document.token_at(7), Some(2));
+        assert_eq!(document.token_at(14), Some(3));
+        assert_eq!(document.token_at(15), None);
+    }
+
+    #[test]
+    fn carries_token_annotations() {
+        let mut document = document("Households quarrel.");
+        let annotation = document.annotation_mut(0).unwrap();
+        annotation.set_lemma("household");
+        annotation.set_part_of_speech(<PartOfSpeech as Noun>::new("household"));
+        annotation.morphology_mut().set("Number", "Plur");
+        document
+            .annotation_mut(2)
+            .unwrap()
+            .set_part_of_speech(<PartOfSpeech as Verb>::new("quarrel"));
+
+        let first = document.token(0).unwrap();
+        assert_eq!(first.lemma(), Some("household"));
+        assert!(matches!(
+            first.part_of_speech(),
+            Some(PartOfSpeech::Noun(_))
+        ));
+        assert_eq!(first.morphology().to_string(), "Number=Plur");
+        assert_eq!(document.token(1).unwrap().morphology().to_string(), "_");
+        let tagged = document
+            .words()
+            .filter(|word| word.part_of_speech().is_some())
+            .count();
+        assert_eq!(tagged, 2);
+    }
+
+    #[test]
+    fn layers_phrases_over_tokens() {
+        let mut document = document("The wall fell. Run.");
+        document.add_phrase(PhraseKind::Clause, 0..5);
+        document.add_phrase(PhraseKind::NounPhrase, 0..3);
+        document.add_phrase(PhraseKind::VerbPhrase, 7..8);
+
+        let kinds: Vec<PhraseKind> = document.phrases_at(2).map(Phrase::kind).collect();
+        assert_eq!(kinds, [PhraseKind::Clause, PhraseKind::NounPhrase]);
+        assert_eq!(document.sentence(0).unwrap().phrases().count(), 2);
+        assert_eq!(document.sentence(1).unwrap().phrases().count(), 1);
+
+        document.set_tokens(VecDeque::new());
+        assert!(document.phrases().is_empty());
+    }
+}
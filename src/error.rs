@@ -0,0 +1,64 @@
+use std::error;
+use std::fmt::{self, Display};
+use std::io;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The input is not valid UTF-8; `offset` is the byte offset of the
+    /// first invalid sequence.
+    Encoding {
+        offset: usize,
+    },
+    /// The text could not be tokenized, e.g. because it looks like binary
+    /// data.
+    Tokenization {
+        offset: usize,
+        message: String,
+    },
+    /// A model or table file is malformed.
+    Parse {
+        line: usize,
+        message: String,
+    },
+    /// A processing pipeline is misconfigured or one of its stages failed.
+    Pipeline {
+        stage: String,
+        message: String,
+    },
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "I/O error: {}", error),
+            Error::Encoding { offset } => write!(f, "invalid UTF-8 at byte {}", offset),
+            Error::Tokenization { offset, message } => {
+                write!(f, "cannot tokenize at byte {}: {}", offset, message)
+            }
+            Error::Parse { line, message } => {
+                write!(f, "parse error on line {}: {}", line, message)
+            }
+            Error::Pipeline { stage, message } => {
+                write!(f, "pipeline error in stage `{}`: {}", stage, message)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Error {
+        Error::Io(error)
+    }
+}
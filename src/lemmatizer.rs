@@ -0,0 +1,187 @@
+use std::collections::VecDeque;
+
+use crate::document::Document;
+use crate::error::Result;
+use crate::parser::comparison;
+use crate::parser::conjugation;
+use crate::parser::declension;
+use crate::parser::inflection;
+use crate::parser::part_of_speech::{PartOfSpeech, PartOfSpeechKind};
+use crate::parser::word_enums::Dialect;
+use crate::processor::{Stage, StageKind};
+use crate::tagger::LexiconTagger;
+use crate::tokenizer::Token;
+use crate::vocabulary::normalize;
+
+/// Reduces words to their dictionary forms using their part of speech.
+///
+/// Nouns are singularized, verbs, auxiliaries and modals conjugated back
+/// to their base form, adjectives taken back to the positive and personal
+/// pronouns to their subject form, all through the rules and exception
+/// tables in [`crate::parser`]. Other words are their own lemma. Lemmas
+/// are lowercase, apart from `I`.
+///
+/// As a pipeline stage it lemmatizes the words a tag stage has tagged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lemmatizer {
+    tagger: LexiconTagger,
+    dialect: Dialect,
+}
+
+impl Lemmatizer {
+    pub fn new() -> Lemmatizer {
+        Lemmatizer::with_tagger(LexiconTagger::default())
+    }
+
+    pub fn with_tagger(tagger: LexiconTagger) -> Lemmatizer {
+        Lemmatizer {
+            tagger,
+            dialect: Dialect::Modern,
+        }
+    }
+
+    /// Recognises the Early Modern English verb forms, such as `hath`
+    /// and `runnest`, in `Dialect::EarlyModern`.
+    pub fn set_dialect(&mut self, dialect: Dialect) {
+        self.dialect = dialect;
+    }
+
+    pub fn dialect(&self) -> Dialect {
+        self.dialect
+    }
+
+    /// The lemma of `word` used as `kind`. The possessive `'s` and `'` of
+    /// nouns and the `'s` of `let's` are dropped.
+    pub fn lemmatize(&self, word: &str, kind: PartOfSpeechKind) -> String {
+        let Some(word) = normalize(word) else {
+            return word.to_string();
+        };
+        let clitic = word.strip_suffix("'s").unwrap_or(&word);
+        match kind {
+            PartOfSpeechKind::Noun => inflection::singularize(clitic.trim_end_matches('\'')),
+            PartOfSpeechKind::Verb | PartOfSpeechKind::Auxiliary | PartOfSpeechKind::Modal => {
+                conjugation::base_form(clitic, self.dialect)
+            }
+            PartOfSpeechKind::Adjective => comparison::base_form(&word).0,
+            PartOfSpeechKind::Pronoun => match declension::paradigm(&word) {
+                Some(paradigm) => paradigm.subject.to_string(),
+                None => word,
+            },
+            _ => word,
+        }
+    }
+
+    /// The lemma of `word`, taking its class from the tagger.
+    pub fn lemmatize_word(&self, word: &str) -> String {
+        self.lemmatize(word, self.tagger.tag_word(word))
+    }
+
+    /// One entry per token: the lemma of each word, tagged in context, and
+    /// `None` for everything else.
+    pub fn lemmas(&self, tokens: &VecDeque<Token>) -> Vec<Option<String>> {
+        let tags = self.tagger.tag(tokens);
+        tokens
+            .iter()
+            .zip(tags)
+            .map(|(token, tag)| match (token.is_word(), tag) {
+                (true, Some(tag)) => Some(self.lemmatize(token.text(), tag.kind())),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl Default for Lemmatizer {
+    fn default() -> Lemmatizer {
+        Lemmatizer::new()
+    }
+}
+
+impl Stage for Lemmatizer {
+    fn kind(&self) -> StageKind {
+        StageKind::Lemmatize
+    }
+
+    fn run(&self, document: &mut Document) -> Result<()> {
+        for index in 0..document.tokens().len() {
+            let token = &document.tokens()[index];
+            if !token.is_word() {
+                continue;
+            }
+            let text = token.text().to_string();
+            if let Some(annotation) = document.annotation_mut(index) {
+                if let Some(kind) = annotation.part_of_speech().map(PartOfSpeech::kind) {
+                    annotation.set_lemma(&self.lemmatize(&text, kind));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::Processor;
+    use crate::tokenizer::Tokenizer;
+    use crate::{Preprocessor, PreprocessorOptions};
+
+    #[test]
+    fn lemmatizes_by_part_of_speech() {
+        let lemmatizer = Lemmatizer::new();
+        let lemma = |word, kind| lemmatizer.lemmatize(word, kind);
+        for word in ["moves", "moved", "moving", "move"] {
+            assert_eq!(lemma(word, PartOfSpeechKind::Verb), "move");
+        }
+        assert_eq!(lemma("Households", PartOfSpeechKind::Noun), "household");
+        assert_eq!(lemma("moves", PartOfSpeechKind::Noun), "move");
+        assert_eq!(lemma("was", PartOfSpeechKind::Auxiliary), "be");
+        assert_eq!(lemma("fairest", PartOfSpeechKind::Adjective), "fair");
+        assert_eq!(lemma("better", PartOfSpeechKind::Adjective), "good");
+        assert_eq!(lemma("me", PartOfSpeechKind::Pronoun), "I");
+        assert_eq!(lemma("thee", PartOfSpeechKind::Pronoun), "thou");
+        assert_eq!(lemma("rest", PartOfSpeechKind::Noun), "rest");
+        assert_eq!(lemma("Romeo's", PartOfSpeechKind::Noun), "romeo");
+        assert_eq!(lemma("parents'", PartOfSpeechKind::Noun), "parent");
+        assert_eq!(lemma("let's", PartOfSpeechKind::Verb), "let");
+        assert_eq!(lemma("hath", PartOfSpeechKind::Verb), "hath");
+
+        let mut archaic = Lemmatizer::new();
+        archaic.set_dialect(Dialect::EarlyModern);
+        assert_eq!(archaic.lemmatize("hath", PartOfSpeechKind::Verb), "have");
+        assert_eq!(
+            archaic.lemmatize("speaketh", PartOfSpeechKind::Verb),
+            "speak"
+        );
+        assert_eq!(archaic.lemmatize("art", PartOfSpeechKind::Auxiliary), "be");
+    }
+
+    #[test]
+    fn lemmatizes_tokens_in_context() {
+        let tokens = Tokenizer::default().tokenize("She loves the houses she visited.");
+        let lemmas: Vec<String> = Lemmatizer::new()
+            .lemmas(&tokens)
+            .into_iter()
+            .flatten()
+            .collect();
+        assert_eq!(lemmas, ["she", "love", "the", "house", "she", "visit"]);
+    }
+
+    #[test]
+    fn runs_after_the_tagger() {
+        let preprocessor =
+            Preprocessor::from_text("Thou art moved.", PreprocessorOptions::default()).unwrap();
+        let mut processor = Processor::new();
+        let mut lemmatizer = Lemmatizer::new();
+        lemmatizer.set_dialect(Dialect::EarlyModern);
+        processor.add_stage(lemmatizer.clone());
+        assert!(processor.validate().is_err());
+
+        let mut processor = Processor::new();
+        processor.add_stage(LexiconTagger::new());
+        processor.add_stage(lemmatizer);
+        let document = processor.process(&preprocessor).unwrap();
+        let lemmas: Vec<&str> = document.words().filter_map(|word| word.lemma()).collect();
+        assert_eq!(lemmas, ["thou", "be", "move"]);
+    }
+}
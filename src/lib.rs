@@ -0,0 +1,43 @@
+//! Tokenization, sentence splitting and word-level grammar for English
+//! text.
+//!
+//! A [`Preprocessor`] reads a whole document into tokens, sentences and a
+//! vocabulary; [`stream`] does the same lazily over any `BufRead`. A
+//! [`Processor`] runs a pipeline of stages over that output to build an
+//! annotated [`Document`], with part-of-speech tags from [`tagger`] and
+//! lemmas from [`Lemmatizer`] or stems from [`Stemmer`], and phrases and
+//! clauses from [`Chunker`] and [`ClauseParser`]. The [`parser`] module
+//! holds the part-of-speech types and traits.
+//!
+//! ```
+//! use langcumen::{Preprocessor, PreprocessorOptions};
+//!
+//! let text = "Two households, both alike in dignity. In fair Verona we lay our scene.";
+//! let preprocessor = Preprocessor::from_text(text, PreprocessorOptions::default())?;
+//! assert_eq!(preprocessor.sentences().count(), 2);
+//! assert_eq!(preprocessor.vocabulary().frequency("in"), 2);
+//! # Ok::<(), langcumen::Error>(())
+//! ```
+
+pub mod chunker;
+pub mod document;
+pub mod error;
+pub mod lemmatizer;
+pub mod parser;
+pub mod processor;
+pub mod sentences;
+pub mod stemmer;
+pub mod stream;
+pub mod tagger;
+pub mod tokenizer;
+pub mod vocabulary;
+
+mod preprocessor;
+
+pub use chunker::{Chunker, ClauseParser};
+pub use document::Document;
+pub use error::{Error, Result};
+pub use lemmatizer::Lemmatizer;
+pub use preprocessor::{Preprocessor, PreprocessorOptions};
+pub use processor::{Processor, Stage, StageKind};
+pub use stemmer::Stemmer;
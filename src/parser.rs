@@ -1,4 +1,9 @@
 pub mod word_enums;
-pub mod word_tokens;
 pub mod word_group_tokens;
 pub mod part_of_speech;
+pub mod inflection;
+pub mod conjugation;
+pub mod comparison;
+pub mod declension;
+pub mod features;
+pub mod tagset;
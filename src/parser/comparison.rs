@@ -0,0 +1,251 @@
+//! English adjective comparison.
+//!
+//! Adjectives of one syllable, and those of two ending in `-y`, `-er`,
+//! `-le` or `-ow`, take `-er` and `-est` with the same spelling rules as
+//! verb suffixes: `big`, `bigger`, `biggest`; `happy`, `happier`. Longer
+//! adjectives and participles such as `tired` compare with `more` and
+//! `most`. `base_form` recognises any of these forms and their degree.
+
+use super::conjugation::{ends_with_consonant_y, has_vowel, restore_stem, stem_for_suffix};
+use super::inflection::match_case;
+use super::word_enums::Degree;
+
+/// The positive, comparative and superlative of adjectives that follow no
+/// rule.
+const IRREGULAR: [(&str, &str, &str); 8] = [
+    ("good", "better", "best"),
+    ("well", "better", "best"),
+    ("bad", "worse", "worst"),
+    ("ill", "worse", "worst"),
+    ("far", "farther", "farthest"),
+    ("little", "less", "least"),
+    ("many", "more", "most"),
+    ("much", "more", "most"),
+];
+
+/// Other comparatives and superlatives that are recognised but never
+/// generated.
+const ALTERNATIVE: [(&str, &str, &str); 2] =
+    [("far", "further", "furthest"), ("old", "elder", "eldest")];
+
+/// Adjectives of one syllable that compare with `more` and `most`.
+const PERIPHRASTIC: [&str; 7] = ["real", "right", "wrong", "just", "fun", "apt", "worth"];
+
+/// Adjectives of two syllables that take `-er` and `-est` although their
+/// ending does not call for it.
+const SUFFIXED: [&str; 7] = [
+    "quiet", "common", "polite", "pleasant", "handsome", "cruel", "stupid",
+];
+
+/// Words ending in `-er` or `-est` that are not comparatives or
+/// superlatives, though their stem would compare to them, as `moth` does
+/// to `mother`.
+const NOT_COMPARED: [&str; 77] = [
+    "after", "anger", "answer", "banner", "bitter", "brother", "butter", "chamber", "chapter",
+    "clever", "corner", "danger", "daughter", "dinner", "eager", "either", "ever", "father",
+    "feather", "finger", "flower", "former", "hammer", "hunger", "inner", "latter", "leather",
+    "letter", "lover", "master", "matter", "monster", "mother", "murder", "neither", "never",
+    "number", "order", "other", "outer", "over", "paper", "peter", "power", "proper", "queer",
+    "rather", "river", "sheer", "silver", "sister", "slender", "sober", "soldier", "summer",
+    "supper", "tender", "thunder", "timber", "tower", "under", "upper", "utter", "water",
+    "weather", "winter", "wonder", "earnest", "forest", "guest", "harvest", "honest", "interest",
+    "modest", "priest", "protest", "quest",
+];
+
+/// `base` in `degree`: `big`, `bigger` or `biggest`.
+pub fn compare(base: &str, degree: Degree) -> String {
+    match degree {
+        Degree::Positive => base.to_string(),
+        Degree::Comparative => comparative(base),
+        Degree::Superlative => superlative(base),
+    }
+}
+
+/// The comparative, as in `bigger` or `more beautiful`.
+pub fn comparative(base: &str) -> String {
+    inflect(base, Degree::Comparative)
+}
+
+/// The superlative, as in `biggest` or `most beautiful`.
+pub fn superlative(base: &str) -> String {
+    inflect(base, Degree::Superlative)
+}
+
+/// The positive form of `word` and the degree it is in: `bigger` gives
+/// `big` and `Comparative`, `most beautiful` gives `beautiful` and
+/// `Superlative`. Words that do not look compared are `Positive`.
+pub fn base_form(word: &str) -> (String, Degree) {
+    if let Some((adverb, rest)) = word.split_once(' ') {
+        match adverb.to_lowercase().as_str() {
+            "more" => return (rest.to_string(), Degree::Comparative),
+            "most" => return (rest.to_string(), Degree::Superlative),
+            _ => {}
+        }
+    }
+    let lowercase = word.to_lowercase();
+    let (base, degree) = positive(&lowercase);
+    (match_case(word, &base), degree)
+}
+
+fn inflect(base: &str, degree: Degree) -> String {
+    let lowercase = base.to_lowercase();
+    if lowercase.is_empty() {
+        return String::new();
+    }
+    let found = IRREGULAR
+        .iter()
+        .find(|(positive, _, _)| *positive == lowercase);
+    if let Some(&(_, comparative, superlative)) = found {
+        let form = match degree {
+            Degree::Superlative => superlative,
+            _ => comparative,
+        };
+        return match_case(base, form);
+    }
+    let (suffix, adverb) = match degree {
+        Degree::Superlative => ("est", "most"),
+        _ => ("er", "more"),
+    };
+    if !takes_suffix(&lowercase) {
+        return format!("{} {}", adverb, base);
+    }
+    let form = if lowercase.ends_with('e') {
+        format!("{}{}", lowercase, &suffix[1..])
+    } else if ends_with_consonant_y(&lowercase) {
+        format!("{}i{}", &lowercase[..lowercase.len() - 1], suffix)
+    } else {
+        format!("{}{}", stem_for_suffix(&lowercase), suffix)
+    };
+    match_case(base, &form)
+}
+
+fn positive(word: &str) -> (String, Degree) {
+    let irregular = IRREGULAR.iter().chain(ALTERNATIVE.iter());
+    for &(positive, comparative, superlative) in irregular {
+        if word == comparative {
+            return (positive.to_string(), Degree::Comparative);
+        }
+        if word == superlative {
+            return (positive.to_string(), Degree::Superlative);
+        }
+    }
+    if NOT_COMPARED.contains(&word) {
+        return (word.to_string(), Degree::Positive);
+    }
+    let suffixes = [("est", Degree::Superlative), ("er", Degree::Comparative)];
+    for (suffix, degree) in suffixes {
+        if let Some(stem) = word.strip_suffix(suffix) {
+            if stem.len() > 1 && has_vowel(stem) {
+                let restored = restore_stem(stem);
+                let silent_e = format!("{}e", restored);
+                let base = match SUFFIXED.contains(&silent_e.as_str()) {
+                    true => silent_e,
+                    false => restored,
+                };
+                if compare(&base, degree) == word {
+                    return (base, degree);
+                }
+            }
+        }
+    }
+    (word.to_string(), Degree::Positive)
+}
+
+/// Whether `word` compares with `-er` and `-est` rather than `more` and
+/// `most`.
+fn takes_suffix(word: &str) -> bool {
+    if PERIPHRASTIC.contains(&word) {
+        return false;
+    }
+    if SUFFIXED.contains(&word) {
+        return true;
+    }
+    match syllables(word) {
+        1 => true,
+        2 => ["y", "er", "le", "ow"]
+            .iter()
+            .any(|ending| word.ends_with(ending)),
+        _ => false,
+    }
+}
+
+/// Syllables in `word`, not counting a silent final `e`.
+fn syllables(word: &str) -> usize {
+    let silent_e = word.ends_with('e') && !word.ends_with("le") && !word.ends_with("ee");
+    let spoken = match silent_e {
+        true => &word[..word.len() - 1],
+        false => word,
+    };
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for c in spoken.chars() {
+        let vowel = "aeiouy".contains(c) && !(c == 'y' && count == 0 && !previous_vowel);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+    count.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORMS: [(&str, &str, &str); 16] = [
+        ("big", "bigger", "biggest"),
+        ("tall", "taller", "tallest"),
+        ("nice", "nicer", "nicest"),
+        ("large", "larger", "largest"),
+        ("free", "freer", "freest"),
+        ("happy", "happier", "happiest"),
+        ("dry", "drier", "driest"),
+        ("grey", "greyer", "greyest"),
+        ("simple", "simpler", "simplest"),
+        ("narrow", "narrower", "narrowest"),
+        ("quiet", "quieter", "quietest"),
+        ("polite", "politer", "politest"),
+        ("good", "better", "best"),
+        ("bad", "worse", "worst"),
+        ("beautiful", "more beautiful", "most beautiful"),
+        ("tired", "more tired", "most tired"),
+    ];
+
+    #[test]
+    fn compares_adjectives() {
+        for (positive, comparative, superlative) in FORMS {
+            assert_eq!(compare(positive, Degree::Positive), positive);
+            assert_eq!(compare(positive, Degree::Comparative), comparative);
+            assert_eq!(compare(positive, Degree::Superlative), superlative);
+        }
+        assert_eq!(compare("honest", Degree::Comparative), "more honest");
+        assert_eq!(compare("Sweet", Degree::Superlative), "Sweetest");
+    }
+
+    #[test]
+    fn recognises_compared_forms() {
+        for (positive, comparative, superlative) in FORMS {
+            let base = positive.to_string();
+            assert_eq!(base_form(positive), (base.clone(), Degree::Positive));
+            assert_eq!(base_form(comparative), (base.clone(), Degree::Comparative));
+            assert_eq!(base_form(superlative), (base, Degree::Superlative));
+        }
+        assert_eq!(
+            base_form("Fairest"),
+            ("Fair".to_string(), Degree::Superlative)
+        );
+        assert_eq!(
+            base_form("eldest"),
+            ("old".to_string(), Degree::Superlative)
+        );
+        assert_eq!(
+            base_form("honest"),
+            ("honest".to_string(), Degree::Positive)
+        );
+        for word in [
+            "clever", "sinister", "mother", "water", "manifest", "forest",
+        ] {
+            assert_eq!(base_form(word), (word.to_string(), Degree::Positive));
+        }
+    }
+}
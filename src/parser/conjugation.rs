@@ -0,0 +1,1029 @@
+//! English verb conjugation.
+//!
+//! Irregular verbs come from a table of principal parts; the others follow
+//! the spelling rules for `-s`, `-ed` and `-ing`: a silent `e` is dropped
+//! (`make`, `making`), a final consonant after a single stressed vowel is
+//! doubled (`run`, `running`), a `y` after a consonant becomes `i`
+//! (`carry`, `carried`) and sibilants take `-es` (`wish`, `wishes`).
+//!
+//! In Early Modern English the second person singular takes `-est` or
+//! `-st` (`thou runnest`, `thou lovedst`) and the third person singular
+//! `-eth` (`he runneth`), with irregulars such as `art`, `hast`, `dost`
+//! and `wert`. `base_form` reverses all of these.
+
+use super::inflection::match_case;
+use super::word_enums::{Aspect, Dialect, Mood, Number, Person, Tense, VerbFeatures, Voice};
+
+/// The base form, simple past and past participle of irregular verbs.
+const IRREGULAR: [(&str, &str, &str); 185] = [
+    ("abide", "abode", "abode"),
+    ("arise", "arose", "arisen"),
+    ("awake", "awoke", "awoken"),
+    ("be", "was", "been"),
+    ("bear", "bore", "borne"),
+    ("beat", "beat", "beaten"),
+    ("become", "became", "become"),
+    ("befall", "befell", "befallen"),
+    ("beget", "begot", "begotten"),
+    ("begin", "began", "begun"),
+    ("behold", "beheld", "beheld"),
+    ("bend", "bent", "bent"),
+    ("beseech", "besought", "besought"),
+    ("bet", "bet", "bet"),
+    ("betake", "betook", "betaken"),
+    ("bid", "bid", "bid"),
+    ("bind", "bound", "bound"),
+    ("bite", "bit", "bitten"),
+    ("bleed", "bled", "bled"),
+    ("blow", "blew", "blown"),
+    ("break", "broke", "broken"),
+    ("breed", "bred", "bred"),
+    ("bring", "brought", "brought"),
+    ("broadcast", "broadcast", "broadcast"),
+    ("build", "built", "built"),
+    ("burst", "burst", "burst"),
+    ("buy", "bought", "bought"),
+    ("cast", "cast", "cast"),
+    ("catch", "caught", "caught"),
+    ("choose", "chose", "chosen"),
+    ("cleave", "clove", "cloven"),
+    ("cling", "clung", "clung"),
+    ("come", "came", "come"),
+    ("cost", "cost", "cost"),
+    ("creep", "crept", "crept"),
+    ("cut", "cut", "cut"),
+    ("deal", "dealt", "dealt"),
+    ("dig", "dug", "dug"),
+    ("do", "did", "done"),
+    ("draw", "drew", "drawn"),
+    ("drink", "drank", "drunk"),
+    ("drive", "drove", "driven"),
+    ("dwell", "dwelt", "dwelt"),
+    ("eat", "ate", "eaten"),
+    ("fall", "fell", "fallen"),
+    ("feed", "fed", "fed"),
+    ("feel", "felt", "felt"),
+    ("fight", "fought", "fought"),
+    ("find", "found", "found"),
+    ("flee", "fled", "fled"),
+    ("fling", "flung", "flung"),
+    ("fly", "flew", "flown"),
+    ("forbear", "forbore", "forborne"),
+    ("forbid", "forbade", "forbidden"),
+    ("foresee", "foresaw", "foreseen"),
+    ("foretell", "foretold", "foretold"),
+    ("forget", "forgot", "forgotten"),
+    ("forgive", "forgave", "forgiven"),
+    ("forsake", "forsook", "forsaken"),
+    ("forswear", "forswore", "forsworn"),
+    ("freeze", "froze", "frozen"),
+    ("get", "got", "gotten"),
+    ("give", "gave", "given"),
+    ("go", "went", "gone"),
+    ("grind", "ground", "ground"),
+    ("grow", "grew", "grown"),
+    ("hang", "hung", "hung"),
+    ("have", "had", "had"),
+    ("hear", "heard", "heard"),
+    ("hew", "hewed", "hewn"),
+    ("hide", "hid", "hidden"),
+    ("hit", "hit", "hit"),
+    ("hold", "held", "held"),
+    ("hurt", "hurt", "hurt"),
+    ("keep", "kept", "kept"),
+    ("kneel", "knelt", "knelt"),
+    ("knit", "knit", "knit"),
+    ("know", "knew", "known"),
+    ("lay", "laid", "laid"),
+    ("lead", "led", "led"),
+    ("leap", "leapt", "leapt"),
+    ("leave", "left", "left"),
+    ("lend", "lent", "lent"),
+    ("let", "let", "let"),
+    ("lie", "lay", "lain"),
+    ("light", "lit", "lit"),
+    ("lose", "lost", "lost"),
+    ("make", "made", "made"),
+    ("mean", "meant", "meant"),
+    ("meet", "met", "met"),
+    ("mistake", "mistook", "mistaken"),
+    ("misunderstand", "misunderstood", "misunderstood"),
+    ("mow", "mowed", "mown"),
+    ("outdo", "outdid", "outdone"),
+    ("overcome", "overcame", "overcome"),
+    ("overhear", "overheard", "overheard"),
+    ("oversee", "oversaw", "overseen"),
+    ("overtake", "overtook", "overtaken"),
+    ("overthrow", "overthrew", "overthrown"),
+    ("pay", "paid", "paid"),
+    ("prove", "proved", "proven"),
+    ("put", "put", "put"),
+    ("quit", "quit", "quit"),
+    ("read", "read", "read"),
+    ("rebuild", "rebuilt", "rebuilt"),
+    ("redo", "redid", "redone"),
+    ("rend", "rent", "rent"),
+    ("retell", "retold", "retold"),
+    ("rid", "rid", "rid"),
+    ("ride", "rode", "ridden"),
+    ("ring", "rang", "rung"),
+    ("rise", "rose", "risen"),
+    ("run", "ran", "run"),
+    ("saw", "sawed", "sawn"),
+    ("say", "said", "said"),
+    ("see", "saw", "seen"),
+    ("seek", "sought", "sought"),
+    ("sell", "sold", "sold"),
+    ("send", "sent", "sent"),
+    ("set", "set", "set"),
+    ("sew", "sewed", "sewn"),
+    ("shake", "shook", "shaken"),
+    ("shear", "sheared", "shorn"),
+    ("shed", "shed", "shed"),
+    ("shine", "shone", "shone"),
+    ("shoot", "shot", "shot"),
+    ("show", "showed", "shown"),
+    ("shrink", "shrank", "shrunk"),
+    ("shut", "shut", "shut"),
+    ("sing", "sang", "sung"),
+    ("sink", "sank", "sunk"),
+    ("sit", "sat", "sat"),
+    ("slay", "slew", "slain"),
+    ("sleep", "slept", "slept"),
+    ("slide", "slid", "slid"),
+    ("sling", "slung", "slung"),
+    ("slit", "slit", "slit"),
+    ("smite", "smote", "smitten"),
+    ("sow", "sowed", "sown"),
+    ("speak", "spoke", "spoken"),
+    ("speed", "sped", "sped"),
+    ("spend", "spent", "spent"),
+    ("spin", "spun", "spun"),
+    ("spit", "spat", "spat"),
+    ("split", "split", "split"),
+    ("spread", "spread", "spread"),
+    ("spring", "sprang", "sprung"),
+    ("stand", "stood", "stood"),
+    ("steal", "stole", "stolen"),
+    ("stick", "stuck", "stuck"),
+    ("sting", "stung", "stung"),
+    ("stink", "stank", "stunk"),
+    ("stride", "strode", "stridden"),
+    ("strike", "struck", "struck"),
+    ("string", "strung", "strung"),
+    ("strive", "strove", "striven"),
+    ("swear", "swore", "sworn"),
+    ("sweep", "swept", "swept"),
+    ("swell", "swelled", "swollen"),
+    ("swim", "swam", "swum"),
+    ("swing", "swung", "swung"),
+    ("take", "took", "taken"),
+    ("teach", "taught", "taught"),
+    ("tear", "tore", "torn"),
+    ("tell", "told", "told"),
+    ("think", "thought", "thought"),
+    ("throw", "threw", "thrown"),
+    ("thrust", "thrust", "thrust"),
+    ("tread", "trod", "trodden"),
+    ("undergo", "underwent", "undergone"),
+    ("understand", "understood", "understood"),
+    ("undertake", "undertook", "undertaken"),
+    ("undo", "undid", "undone"),
+    ("uphold", "upheld", "upheld"),
+    ("wake", "woke", "woken"),
+    ("wear", "wore", "worn"),
+    ("weave", "wove", "woven"),
+    ("weep", "wept", "wept"),
+    ("win", "won", "won"),
+    ("wind", "wound", "wound"),
+    ("withdraw", "withdrew", "withdrawn"),
+    ("withhold", "withheld", "withheld"),
+    ("withstand", "withstood", "withstood"),
+    ("wring", "wrung", "wrung"),
+    ("write", "wrote", "written"),
+];
+
+/// Finite forms that are not the base form followed by a suffix, and
+/// the verbs they belong to.
+const FINITE: [(&str, &str); 6] = [
+    ("am", "be"),
+    ("is", "be"),
+    ("are", "be"),
+    ("were", "be"),
+    ("has", "have"),
+    ("does", "do"),
+];
+
+/// Early Modern English forms that follow no rule, and the verbs they
+/// belong to.
+const ARCHAIC: [(&str, &str); 26] = [
+    ("art", "be"),
+    ("wast", "be"),
+    ("wert", "be"),
+    ("beest", "be"),
+    ("hast", "have"),
+    ("hath", "have"),
+    ("hadst", "have"),
+    ("dost", "do"),
+    ("doth", "do"),
+    ("didst", "do"),
+    ("doest", "do"),
+    ("doeth", "do"),
+    ("saith", "say"),
+    ("shalt", "shall"),
+    ("wilt", "will"),
+    ("canst", "can"),
+    ("mayst", "may"),
+    ("mayest", "may"),
+    ("couldst", "could"),
+    ("couldest", "could"),
+    ("wouldst", "would"),
+    ("wouldest", "would"),
+    ("shouldst", "should"),
+    ("shouldest", "should"),
+    ("mightst", "might"),
+    ("mightest", "might"),
+];
+
+const MODALS: [&str; 9] = [
+    "can", "could", "may", "might", "must", "shall", "should", "will", "would",
+];
+
+/// Verbs whose base form ends in `-est`, which is not a suffix on them.
+const EST_VERBS: [&str; 21] = [
+    "arrest", "attest", "contest", "crest", "detest", "digest", "divest", "harvest", "infest",
+    "invest", "jest", "manifest", "molest", "nest", "protest", "quest", "request", "rest",
+    "suggest", "test", "wrest",
+];
+
+/// Verbs whose base form ends in `-eed`, which is not a past ending on them.
+const EED_VERBS: [&str; 12] = [
+    "bleed", "breed", "exceed", "feed", "heed", "need", "plead", "proceed", "seed", "speed",
+    "succeed", "weed",
+];
+
+/// Verbs in a single `z`, which double it before a suffix as in `quizzes`.
+/// A doubled `zz` is otherwise part of the base, as in `buzzes`.
+const DOUBLED_Z: [&str; 3] = ["fez", "quiz", "whiz"];
+
+/// Verbs that keep their final `e` before `-ing`, as in `singeing`, where
+/// dropping it would spell another verb. Their `-ed` forms are read back
+/// to them too, as `singed` is.
+const KEEPS_E: [&str; 4] = ["be", "singe", "swinge", "tinge"];
+
+/// Verbs in `-ic`, which take `-ck` before a suffix.
+const IC_VERBS: [&str; 5] = ["frolic", "mimic", "panic", "picnic", "traffic"];
+
+/// Verbs of more than one syllable stressed on the last, which double
+/// their final consonant like `run` does.
+const STRESSED_FINAL: [&str; 36] = [
+    "abet", "acquit", "admit", "allot", "begin", "commit", "compel", "concur", "confer", "control",
+    "defer", "deter", "embed", "equip", "excel", "expel", "extol", "forget", "impel", "incur",
+    "infer", "occur", "omit", "outwit", "patrol", "permit", "prefer", "propel", "rebel", "rebut",
+    "recur", "refer", "regret", "repel", "submit", "transfer",
+];
+
+/// The simple past, as in `I ran`.
+pub fn past_tense(base: &str) -> String {
+    inflect(base, |word| match irregular(word) {
+        Some((_, past, _)) => past.to_string(),
+        None => regular_past(word),
+    })
+}
+
+/// The past participle, as in `I have run`.
+pub fn past_participle(base: &str) -> String {
+    inflect(base, |word| match irregular(word) {
+        Some((_, _, participle)) => participle.to_string(),
+        None => regular_past(word),
+    })
+}
+
+/// The `-ing` form, as in `I am running`.
+pub fn present_participle(base: &str) -> String {
+    inflect(base, |word| {
+        if let Some(stem) = word.strip_suffix("ie") {
+            return format!("{}ying", stem);
+        }
+        let keeps_e = KEEPS_E.contains(&word)
+            || ["ee", "ye", "oe"]
+                .iter()
+                .any(|ending| word.ends_with(ending));
+        match word.strip_suffix('e') {
+            Some(stem) if !keeps_e && !stem.is_empty() => format!("{}ing", stem),
+            _ => format!("{}ing", stem_for_suffix(word)),
+        }
+    })
+}
+
+/// The third person singular present, as in `she runs`.
+pub fn third_person_singular(base: &str) -> String {
+    inflect(base, |word| match word {
+        "be" => "is".to_string(),
+        "have" => "has".to_string(),
+        _ if ends_with_consonant_y(word) => format!("{}ies", &word[..word.len() - 1]),
+        _ if word.ends_with('z') => format!("{}es", stem_for_suffix(word)),
+        _ if ["s", "x", "ch", "sh"]
+            .iter()
+            .any(|ending| word.ends_with(ending))
+            || ends_with_consonant_o(word) =>
+        {
+            format!("{}es", word)
+        }
+        _ => format!("{}s", word),
+    })
+}
+
+/// The present for plural subjects, as in `they run`.
+pub fn present_plural(base: &str) -> String {
+    inflect(base, |word| match word {
+        "be" => "are".to_string(),
+        _ => word.to_string(),
+    })
+}
+
+/// The Early Modern English second person singular present, as in `thou
+/// runnest`.
+pub fn archaic_second_person(base: &str) -> String {
+    inflect(base, |word| match word {
+        "be" => "art".to_string(),
+        "have" => "hast".to_string(),
+        "do" => "dost".to_string(),
+        "shall" => "shalt".to_string(),
+        "will" => "wilt".to_string(),
+        "can" => "canst".to_string(),
+        "may" => "mayst".to_string(),
+        "must" => "must".to_string(),
+        "could" | "would" | "should" | "might" => format!("{}st", word),
+        _ => archaic_suffix(word, "est"),
+    })
+}
+
+/// The Early Modern English second person singular past, as in `thou
+/// rannest` or `thou lovedst`.
+pub fn archaic_second_person_past(base: &str) -> String {
+    inflect(base, |word| match word {
+        "be" => "wast".to_string(),
+        "have" => "hadst".to_string(),
+        "do" => "didst".to_string(),
+        _ => {
+            let past = past_tense(word);
+            match past.ends_with('e') || past.ends_with('d') {
+                true => format!("{}st", past),
+                false => format!("{}est", stem_for_suffix(&past)),
+            }
+        }
+    })
+}
+
+/// The Early Modern English third person singular present, as in `he
+/// runneth`.
+pub fn archaic_third_person(base: &str) -> String {
+    inflect(base, |word| match word {
+        "be" => "is".to_string(),
+        "have" => "hath".to_string(),
+        "do" => "doth".to_string(),
+        "say" => "saith".to_string(),
+        _ if MODALS.contains(&word) => word.to_string(),
+        _ => archaic_suffix(word, "eth"),
+    })
+}
+
+/// The verb phrase for `base` with `features`, auxiliaries included:
+/// `will have been running`, `would go`, `was taken`. Imperatives and
+/// present subjunctives use the base form whatever the person.
+pub fn conjugate(base: &str, features: &VerbFeatures) -> String {
+    let mut chain: Vec<(&str, Form)> = Vec::new();
+    let modal = match (features.mood, features.tense) {
+        (Mood::Conditional, _) => Some("would"),
+        (Mood::Indicative, Tense::Future) => Some("will"),
+        _ => None,
+    };
+    if matches!(
+        features.aspect,
+        Aspect::Perfect | Aspect::PerfectProgressive
+    ) {
+        chain.push(("have", Form::PastParticiple));
+    }
+    if matches!(
+        features.aspect,
+        Aspect::Progressive | Aspect::PerfectProgressive
+    ) {
+        chain.push(("be", Form::PresentParticiple));
+    }
+    if features.voice == Voice::Passive {
+        chain.push(("be", Form::PastParticiple));
+    }
+    chain.push((base, Form::Base));
+
+    // Each verb takes the form the one before it asks for; the first is
+    // finite unless a modal comes before it.
+    let mut words: Vec<String> = modal
+        .map(|modal| match thou(features) {
+            true => archaic_second_person(modal),
+            false => modal.to_string(),
+        })
+        .into_iter()
+        .collect();
+    let mut required = modal.map(|_| Form::Base);
+    for (verb, next) in chain {
+        words.push(match required {
+            Some(Form::Base) => verb.to_string(),
+            Some(Form::PastParticiple) => past_participle(verb),
+            Some(Form::PresentParticiple) => present_participle(verb),
+            None => finite(verb, features),
+        });
+        required = Some(next);
+    }
+    words.join(" ")
+}
+
+/// The form a verb in a chain requires of the next one.
+#[derive(Clone, Copy)]
+enum Form {
+    Base,
+    PresentParticiple,
+    PastParticiple,
+}
+
+/// The form that agrees with the subject, in the present or past.
+fn finite(verb: &str, features: &VerbFeatures) -> String {
+    let singular = features.number == Number::Singular;
+    let lowercase = verb.to_lowercase();
+    let past = features.tense == Tense::Past;
+    let archaic = features.dialect == Dialect::EarlyModern;
+    let form = match (features.mood, lowercase.as_str()) {
+        (Mood::Imperative, _) => verb.to_string(),
+        (Mood::Subjunctive, "be") if past && thou(features) => "wert".to_string(),
+        (Mood::Subjunctive, "be") if past => "were".to_string(),
+        (Mood::Subjunctive, _) if !past => verb.to_string(),
+        _ if thou(features) && past => archaic_second_person_past(verb),
+        _ if thou(features) => archaic_second_person(verb),
+        _ if archaic && !past && singular && features.person == Person::Third => {
+            archaic_third_person(verb)
+        }
+        (_, "be") if past => match singular && features.person != Person::Second {
+            true => "was".to_string(),
+            false => "were".to_string(),
+        },
+        (_, "be") => match (features.person, singular) {
+            (Person::First, true) => "am".to_string(),
+            (Person::Third, true) => "is".to_string(),
+            _ => "are".to_string(),
+        },
+        _ if past => past_tense(verb),
+        _ if singular && features.person == Person::Third => third_person_singular(verb),
+        _ => verb.to_string(),
+    };
+    match_case(verb, &form)
+}
+
+/// Whether `features` call for the Early Modern English `thou` forms.
+fn thou(features: &VerbFeatures) -> bool {
+    features.dialect == Dialect::EarlyModern
+        && features.person == Person::Second
+        && features.number == Number::Singular
+}
+
+/// The base form of any form of a verb: `ran`, `running` and `runs` give
+/// `run`. Early Modern English forms such as `runnest`, `runneth`,
+/// `runn'st` and `hath` are recognised in `Dialect::EarlyModern`. Words
+/// that do not look inflected are returned as they are.
+pub fn base_form(word: &str, dialect: Dialect) -> String {
+    let lowercase = word.to_lowercase().replace('\u{2019}', "'");
+    let base = match dialect {
+        Dialect::EarlyModern => archaic_base(&lowercase),
+        Dialect::Modern => None,
+    };
+    match_case(word, &base.unwrap_or_else(|| modern_base(&lowercase)))
+}
+
+fn archaic_base(word: &str) -> Option<String> {
+    if let Some(&(_, base)) = ARCHAIC.iter().find(|(form, _)| *form == word) {
+        return Some(base.to_string());
+    }
+    if let Some(stem) = word.strip_suffix("'d") {
+        return Some(restore_stem(stem));
+    }
+    if let Some(past) = word.strip_suffix("dst") {
+        return Some(modern_base(&format!("{}d", past)));
+    }
+    if word.ends_with("eeth") || word.ends_with("eest") {
+        return Some(word[..word.len() - 2].to_string());
+    }
+    if let Some(stem) = word.strip_suffix("eth").filter(|stem| has_vowel(stem)) {
+        return Some(restore_stem(stem));
+    }
+    // `-est` and `-'st` also follow the past, as in `thou camest`.
+    let stem = word
+        .strip_suffix("'st")
+        .or_else(|| {
+            word.strip_suffix("est")
+                .filter(|_| !EST_VERBS.contains(&word))
+        })
+        .filter(|stem| has_vowel(stem))?;
+    let restored = restore_stem(stem);
+    Some(
+        irregular_base(stem)
+            .or_else(|| irregular_base(&restored))
+            .unwrap_or(restored),
+    )
+}
+
+/// The verb whose irregular past or past participle is `word`.
+fn irregular_base(word: &str) -> Option<String> {
+    IRREGULAR
+        .iter()
+        .find(|(_, past, participle)| *past == word || *participle == word)
+        .map(|(base, _, _)| base.to_string())
+}
+
+fn modern_base(word: &str) -> String {
+    if let Some(&(_, base)) = FINITE.iter().find(|(form, _)| *form == word) {
+        return base.to_string();
+    }
+    if let Some(base) = irregular_base(word) {
+        return base;
+    }
+    if irregular(word).is_some() {
+        return word.to_string();
+    }
+    if let Some(stem) = word.strip_suffix("ying").filter(|stem| stem.len() == 1) {
+        return format!("{}ie", stem);
+    }
+    if let Some(stem) = word.strip_suffix("ing").filter(|stem| has_vowel(stem)) {
+        return match stem.ends_with('e') {
+            true => stem.to_string(),
+            false => restore_stem(stem),
+        };
+    }
+    if let Some(stem) = word
+        .strip_suffix("ied")
+        .or_else(|| word.strip_suffix("ies"))
+    {
+        return match stem.len() {
+            0 => word.to_string(),
+            1 => format!("{}ie", stem),
+            _ => format!("{}y", stem),
+        };
+    }
+    if word.ends_with("eed") {
+        return match EED_VERBS.iter().any(|verb| word.ends_with(verb)) {
+            true => word.to_string(),
+            false => word[..word.len() - 1].to_string(),
+        };
+    }
+    if let Some(stem) = word.strip_suffix("ed").filter(|stem| has_vowel(stem)) {
+        let silent_e = format!("{}e", stem);
+        return match KEEPS_E.contains(&silent_e.as_str()) {
+            true => silent_e,
+            false => restore_stem(stem),
+        };
+    }
+    if let Some(stem) = word.strip_suffix("es") {
+        let takes_es = ["ss", "sh", "ch", "x", "zz"]
+            .iter()
+            .any(|ending| stem.ends_with(ending))
+            || ends_with_consonant_o(stem);
+        if takes_es {
+            return restore_stem(stem);
+        }
+    }
+    let looks_uninflected = ["ss", "us", "is"]
+        .iter()
+        .any(|ending| word.ends_with(ending));
+    match word.strip_suffix('s') {
+        Some(stem) if !looks_uninflected && has_vowel(stem) => stem.to_string(),
+        _ => word.to_string(),
+    }
+}
+
+/// Undoes the spelling changes made before a suffix: `runn` gives `run`,
+/// `carri` gives `carry`, `mak` and `lov` give `make` and `love`.
+pub(crate) fn restore_stem(stem: &str) -> String {
+    if let Some(z) = stem.strip_suffix('z').filter(|z| DOUBLED_Z.contains(z)) {
+        return z.to_string();
+    }
+    if let Some(ic) = stem.strip_suffix('k').filter(|ic| IC_VERBS.contains(ic)) {
+        return ic.to_string();
+    }
+    let chars: Vec<char> = stem.chars().collect();
+    match chars.as_slice() {
+        [_, _, .., 'i'] => return format!("{}y", &stem[..stem.len() - 1]),
+        [_, 'i'] => return format!("{}e", stem),
+        [.., a, b] if a == b && !is_vowel(*a) && !"lsfz".contains(*a) => {
+            let single = &stem[..stem.len() - b.len_utf8()];
+            if stem_for_suffix(single) == stem {
+                return single.to_string();
+            }
+        }
+        _ => {}
+    }
+    let single_vowel = |ending: &str| {
+        stem.strip_suffix(ending)
+            .and_then(|rest| rest.chars().last())
+            .is_some_and(|c| !is_vowel(c))
+    };
+    let silent_e = match chars.as_slice() {
+        [.., 'o', 'u'] => false,
+        [.., 'v' | 'u' | 'c'] | [.., 'd' | 'r', 'g'] | [.., 'i', 'z'] | ['u', 's'] => true,
+        // `bathe` and `breathe`, but not `smooth` or `mouth`.
+        [.., a, 't', 'h'] => is_vowel(*a) && !stem.ends_with("ooth") && !stem.ends_with("outh"),
+        [.., _, before, 'e'] if !is_vowel(*before) => true,
+        [.., _, before, 'l'] if !is_vowel(*before) && !"lrw".contains(*before) => true,
+        [.., a, b, 's' | 'z'] if is_vowel(*a) && is_vowel(*b) => true,
+        [.., before, vowel, last] if syllables(stem) == 1 => {
+            !is_vowel(*before) && is_vowel(*vowel) && !is_vowel(*last) && !"wxy".contains(*last)
+        }
+        _ => {
+            syllables(stem) > 1
+                && ["at", "id", "ir", "ur", "ut", "in", "os", "is", "ar"]
+                    .iter()
+                    .any(|ending| single_vowel(ending))
+        }
+    };
+    match silent_e {
+        true => format!("{}e", stem),
+        false => stem.to_string(),
+    }
+}
+
+/// Whether `word` is a modal such as `shall`, or an Early Modern form of
+/// one such as `shalt`.
+pub fn is_modal(word: &str) -> bool {
+    let lowercase = word.to_lowercase();
+    let base = ARCHAIC
+        .iter()
+        .find(|(form, _)| *form == lowercase)
+        .map_or(lowercase.as_str(), |(_, base)| base);
+    MODALS.contains(&base)
+}
+
+/// Whether the past forms of `base` come from the irregular table.
+pub fn is_irregular(base: &str) -> bool {
+    irregular(&base.to_lowercase()).is_some()
+}
+
+fn inflect(base: &str, rule: impl Fn(&str) -> String) -> String {
+    let lowercase = base.to_lowercase();
+    if lowercase.is_empty() {
+        return String::new();
+    }
+    match_case(base, &rule(&lowercase))
+}
+
+fn irregular(word: &str) -> Option<(&'static str, &'static str, &'static str)> {
+    IRREGULAR.iter().find(|(base, _, _)| *base == word).copied()
+}
+
+fn regular_past(word: &str) -> String {
+    if word.ends_with('e') {
+        format!("{}d", word)
+    } else if ends_with_consonant_y(word) {
+        format!("{}ied", &word[..word.len() - 1])
+    } else {
+        format!("{}ed", stem_for_suffix(word))
+    }
+}
+
+/// `word` ready for a suffix starting with a vowel: with its final
+/// consonant doubled if it needs to be, or `-c` turned into `-ck`.
+pub(crate) fn stem_for_suffix(word: &str) -> String {
+    if word.ends_with('c') && word.len() > 4 {
+        return format!("{}k", word);
+    }
+    let chars: Vec<char> = word.chars().collect();
+    let doubles = match chars.as_slice() {
+        [.., before, vowel, last] => {
+            // The `u` of `qu` is a consonant here, as in `quit`.
+            let after_consonant = !is_vowel(*before) || chars.ends_with(&['q', 'u', *vowel, *last]);
+            after_consonant
+                && is_vowel(*vowel)
+                && !is_vowel(*last)
+                && !"wxy".contains(*last)
+                && (syllables(word) == 1 || STRESSED_FINAL.contains(&word))
+        }
+        _ => false,
+    };
+    match doubles {
+        true => format!("{}{}", word, chars[chars.len() - 1]),
+        false => word.to_string(),
+    }
+}
+
+/// `word` with a suffix starting with `e`, as in `lovest`, `seeth` and
+/// `carrieth`.
+fn archaic_suffix(word: &str, suffix: &str) -> String {
+    if word.ends_with("ee") {
+        return format!("{}{}", word, &suffix[1..]);
+    }
+    match word.strip_suffix('e') {
+        Some(stem) if !stem.is_empty() => format!("{}{}", stem, suffix),
+        _ if ends_with_consonant_y(word) => format!("{}i{}", &word[..word.len() - 1], suffix),
+        _ => format!("{}{}", stem_for_suffix(word), suffix),
+    }
+}
+
+pub(crate) fn has_vowel(word: &str) -> bool {
+    word.chars().any(|c| is_vowel(c) || c == 'y')
+}
+
+fn syllables(word: &str) -> usize {
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for c in word.chars() {
+        let vowel = is_vowel(c);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+    count
+}
+
+pub(crate) fn ends_with_consonant_y(word: &str) -> bool {
+    let mut chars = word.chars().rev();
+    chars.next() == Some('y') && chars.next().is_some_and(|c| !is_vowel(c))
+}
+
+fn ends_with_consonant_o(word: &str) -> bool {
+    let mut chars = word.chars().rev();
+    chars.next() == Some('o') && chars.next().is_some_and(|c| !is_vowel(c))
+}
+
+fn is_vowel(c: char) -> bool {
+    "aeiou".contains(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_the_spelling_rules() {
+        let forms = |base| {
+            (
+                third_person_singular(base),
+                past_tense(base),
+                present_participle(base),
+            )
+        };
+        let expected = |third: &str, past: &str, participle: &str| {
+            (third.to_string(), past.to_string(), participle.to_string())
+        };
+        assert_eq!(forms("walk"), expected("walks", "walked", "walking"));
+        assert_eq!(forms("move"), expected("moves", "moved", "moving"));
+        assert_eq!(forms("stop"), expected("stops", "stopped", "stopping"));
+        assert_eq!(forms("carry"), expected("carries", "carried", "carrying"));
+        assert_eq!(forms("play"), expected("plays", "played", "playing"));
+        assert_eq!(forms("wish"), expected("wishes", "wished", "wishing"));
+        assert_eq!(forms("fix"), expected("fixes", "fixed", "fixing"));
+        assert_eq!(forms("agree"), expected("agrees", "agreed", "agreeing"));
+        assert_eq!(forms("die"), expected("dies", "died", "dying"));
+        assert_eq!(forms("quiz"), expected("quizzes", "quizzed", "quizzing"));
+        assert_eq!(forms("buzz"), expected("buzzes", "buzzed", "buzzing"));
+        assert_eq!(forms("bathe"), expected("bathes", "bathed", "bathing"));
+        assert_eq!(forms("singe"), expected("singes", "singed", "singeing"));
+        assert_eq!(forms("visit"), expected("visits", "visited", "visiting"));
+        assert_eq!(
+            forms("prefer"),
+            expected("prefers", "preferred", "preferring")
+        );
+        assert_eq!(forms("panic"), expected("panics", "panicked", "panicking"));
+        assert_eq!(forms("echo"), expected("echoes", "echoed", "echoing"));
+        assert_eq!(forms("rain"), expected("rains", "rained", "raining"));
+        assert_eq!(present_participle("quit"), "quitting");
+    }
+
+    #[test]
+    fn builds_verb_phrases_from_features() {
+        let conjugated = |base, features| conjugate(base, &features);
+        let features = VerbFeatures::default();
+        assert_eq!(conjugated("run", features), "runs");
+        assert_eq!(
+            conjugated(
+                "run",
+                VerbFeatures {
+                    tense: Tense::Future,
+                    aspect: Aspect::PerfectProgressive,
+                    ..features
+                }
+            ),
+            "will have been running"
+        );
+        assert_eq!(
+            conjugated(
+                "go",
+                VerbFeatures {
+                    mood: Mood::Conditional,
+                    ..features
+                }
+            ),
+            "would go"
+        );
+        assert_eq!(
+            conjugated(
+                "take",
+                VerbFeatures {
+                    tense: Tense::Past,
+                    voice: Voice::Passive,
+                    ..features
+                }
+            ),
+            "was taken"
+        );
+        assert_eq!(
+            conjugated(
+                "take",
+                VerbFeatures {
+                    aspect: Aspect::Progressive,
+                    voice: Voice::Passive,
+                    person: Person::First,
+                    ..features
+                }
+            ),
+            "am being taken"
+        );
+        assert_eq!(
+            conjugated(
+                "move",
+                VerbFeatures {
+                    tense: Tense::Past,
+                    aspect: Aspect::Perfect,
+                    number: Number::Plural,
+                    ..features
+                }
+            ),
+            "had moved"
+        );
+        assert_eq!(
+            conjugated(
+                "be",
+                VerbFeatures {
+                    tense: Tense::Past,
+                    mood: Mood::Subjunctive,
+                    person: Person::First,
+                    ..features
+                }
+            ),
+            "were"
+        );
+        assert_eq!(
+            conjugated(
+                "go",
+                VerbFeatures {
+                    mood: Mood::Subjunctive,
+                    ..features
+                }
+            ),
+            "go"
+        );
+    }
+
+    #[test]
+    fn conjugates_irregular_verbs() {
+        assert_eq!(past_tense("run"), "ran");
+        assert_eq!(past_participle("run"), "run");
+        assert_eq!(present_participle("run"), "running");
+        assert_eq!(past_tense("go"), "went");
+        assert_eq!(third_person_singular("go"), "goes");
+        assert_eq!(past_participle("take"), "taken");
+        assert_eq!(present_participle("make"), "making");
+        assert_eq!(third_person_singular("have"), "has");
+        assert_eq!(present_participle("be"), "being");
+        assert_eq!(present_plural("be"), "are");
+        assert_eq!(past_tense("Begin"), "Began");
+        assert!(is_irregular("thrust"));
+        assert!(!is_irregular("move"));
+    }
+
+    #[test]
+    fn conjugates_early_modern_english() {
+        let thou = VerbFeatures {
+            person: Person::Second,
+            dialect: Dialect::EarlyModern,
+            ..VerbFeatures::default()
+        };
+        let he = VerbFeatures {
+            dialect: Dialect::EarlyModern,
+            ..VerbFeatures::default()
+        };
+        let past = |features| VerbFeatures {
+            tense: Tense::Past,
+            ..features
+        };
+        assert_eq!(conjugate("run", &thou), "runnest");
+        assert_eq!(conjugate("love", &thou), "lovest");
+        assert_eq!(conjugate("see", &thou), "seest");
+        assert_eq!(conjugate("be", &thou), "art");
+        assert_eq!(conjugate("have", &thou), "hast");
+        assert_eq!(conjugate("do", &thou), "dost");
+        assert_eq!(conjugate("be", &past(thou)), "wast");
+        assert_eq!(conjugate("have", &past(thou)), "hadst");
+        assert_eq!(conjugate("love", &past(thou)), "lovedst");
+        assert_eq!(conjugate("run", &past(thou)), "rannest");
+        assert_eq!(
+            conjugate(
+                "go",
+                &VerbFeatures {
+                    tense: Tense::Future,
+                    ..thou
+                }
+            ),
+            "wilt go"
+        );
+        assert_eq!(
+            conjugate(
+                "be",
+                &VerbFeatures {
+                    mood: Mood::Subjunctive,
+                    ..past(thou)
+                }
+            ),
+            "wert"
+        );
+        assert_eq!(conjugate("run", &he), "runneth");
+        assert_eq!(conjugate("carry", &he), "carrieth");
+        assert_eq!(conjugate("have", &he), "hath");
+        assert_eq!(conjugate("do", &he), "doth");
+        assert_eq!(conjugate("be", &he), "is");
+        assert_eq!(
+            conjugate(
+                "be",
+                &VerbFeatures {
+                    number: Number::Plural,
+                    ..thou
+                }
+            ),
+            "are"
+        );
+    }
+
+    #[test]
+    fn recovers_base_forms() {
+        let modern = |word| base_form(word, Dialect::Modern);
+        let archaic = |word| base_form(word, Dialect::EarlyModern);
+        for (form, base) in [
+            ("ran", "run"),
+            ("running", "run"),
+            ("runs", "run"),
+            ("moved", "move"),
+            ("making", "make"),
+            ("stopped", "stop"),
+            ("carried", "carry"),
+            ("carries", "carry"),
+            ("dying", "die"),
+            ("wishes", "wish"),
+            ("goes", "go"),
+            ("agreed", "agree"),
+            ("needed", "need"),
+            ("added", "add"),
+            ("prepared", "prepare"),
+            ("panicked", "panic"),
+            ("danced", "dance"),
+            ("visited", "visit"),
+            ("is", "be"),
+            ("were", "be"),
+            ("walk", "walk"),
+            ("bless", "bless"),
+            ("Spoke", "Speak"),
+            ("quizzes", "quiz"),
+            ("quizzed", "quiz"),
+            ("buzzes", "buzz"),
+            ("bathed", "bathe"),
+            ("breathing", "breathe"),
+            ("smoothed", "smooth"),
+            ("singeing", "singe"),
+            ("singed", "singe"),
+            ("seeing", "see"),
+        ] {
+            assert_eq!(modern(form), base, "base form of {}", form);
+        }
+        for (form, base) in [
+            ("art", "be"),
+            ("hath", "have"),
+            ("dost", "do"),
+            ("wert", "be"),
+            ("runnest", "run"),
+            ("runn'st", "run"),
+            ("rooteth", "root"),
+            ("speaketh", "speak"),
+            ("loveth", "love"),
+            ("lov'd", "love"),
+            ("goeth", "go"),
+            ("seest", "see"),
+            ("carrieth", "carry"),
+            ("lovedst", "love"),
+            ("saidst", "say"),
+            ("rest", "rest"),
+            ("camest", "come"),
+            ("went'st", "go"),
+            ("liest", "lie"),
+            ("stumblest", "stumble"),
+            ("moved", "move"),
+        ] {
+            assert_eq!(archaic(form), base, "base form of {}", form);
+        }
+        assert_eq!(modern("runneth"), "runneth");
+    }
+}
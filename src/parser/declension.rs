@@ -0,0 +1,293 @@
+//! English personal pronoun paradigms.
+//!
+//! Each paradigm holds the subject, object, possessive, independent
+//! possessive and reflexive forms of one personal pronoun: `I`, `me`,
+//! `my`, `mine`, `myself`. The Early Modern English `thou` and `ye` are
+//! included alongside the modern pronouns, and any form finds its
+//! paradigm.
+
+use super::word_enums::{Dialect, Gender, Number, Person};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Paradigm {
+    pub subject: &'static str,
+    pub object: &'static str,
+    pub possessive: &'static str,
+    pub independent_possessive: &'static str,
+    pub reflexive: &'static str,
+    pub person: Person,
+    pub gender: Gender,
+    pub number: Number,
+    pub dialect: Dialect,
+}
+
+impl Paradigm {
+    /// All five forms, subject first.
+    pub fn forms(&self) -> [&'static str; 5] {
+        [
+            self.subject,
+            self.object,
+            self.possessive,
+            self.independent_possessive,
+            self.reflexive,
+        ]
+    }
+
+    /// Whether `form` is one of the forms of this pronoun, in any case.
+    pub fn contains(&self, form: &str) -> bool {
+        self.forms()
+            .iter()
+            .any(|candidate| candidate.eq_ignore_ascii_case(form))
+    }
+}
+
+/// The five forms of one pronoun, owned so that pronouns outside
+/// `PARADIGMS` can be held too.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PronounForms {
+    pub subject: String,
+    pub object: String,
+    pub possessive: String,
+    pub independent_possessive: String,
+    pub reflexive: String,
+}
+
+impl PronounForms {
+    /// The forms of the paradigm `form` belongs to, or `form` in every
+    /// position if it has none, as for `who`.
+    pub fn of(form: &str) -> PronounForms {
+        match paradigm(form) {
+            Some(paradigm) => PronounForms::from(paradigm),
+            None => PronounForms {
+                subject: form.to_string(),
+                object: form.to_string(),
+                possessive: form.to_string(),
+                independent_possessive: form.to_string(),
+                reflexive: form.to_string(),
+            },
+        }
+    }
+}
+
+impl From<&Paradigm> for PronounForms {
+    fn from(paradigm: &Paradigm) -> PronounForms {
+        PronounForms {
+            subject: paradigm.subject.to_string(),
+            object: paradigm.object.to_string(),
+            possessive: paradigm.possessive.to_string(),
+            independent_possessive: paradigm.independent_possessive.to_string(),
+            reflexive: paradigm.reflexive.to_string(),
+        }
+    }
+}
+
+const fn paradigm_of(
+    forms: [&'static str; 5],
+    person: Person,
+    gender: Gender,
+    number: Number,
+    dialect: Dialect,
+) -> Paradigm {
+    let [subject, object, possessive, independent_possessive, reflexive] = forms;
+    Paradigm {
+        subject,
+        object,
+        possessive,
+        independent_possessive,
+        reflexive,
+        person,
+        gender,
+        number,
+        dialect,
+    }
+}
+
+/// The personal pronouns, most common first where a form is shared:
+/// `you` is found as the singular and `her` as the object.
+pub const PARADIGMS: [Paradigm; 11] = [
+    paradigm_of(
+        ["I", "me", "my", "mine", "myself"],
+        Person::First,
+        Gender::Neutral,
+        Number::Singular,
+        Dialect::Modern,
+    ),
+    paradigm_of(
+        ["we", "us", "our", "ours", "ourselves"],
+        Person::First,
+        Gender::Neutral,
+        Number::Plural,
+        Dialect::Modern,
+    ),
+    paradigm_of(
+        ["you", "you", "your", "yours", "yourself"],
+        Person::Second,
+        Gender::Neutral,
+        Number::Singular,
+        Dialect::Modern,
+    ),
+    paradigm_of(
+        ["you", "you", "your", "yours", "yourselves"],
+        Person::Second,
+        Gender::Neutral,
+        Number::Plural,
+        Dialect::Modern,
+    ),
+    paradigm_of(
+        ["thou", "thee", "thy", "thine", "thyself"],
+        Person::Second,
+        Gender::Neutral,
+        Number::Singular,
+        Dialect::EarlyModern,
+    ),
+    paradigm_of(
+        ["ye", "you", "your", "yours", "yourselves"],
+        Person::Second,
+        Gender::Neutral,
+        Number::Plural,
+        Dialect::EarlyModern,
+    ),
+    paradigm_of(
+        ["he", "him", "his", "his", "himself"],
+        Person::Third,
+        Gender::Masculine,
+        Number::Singular,
+        Dialect::Modern,
+    ),
+    paradigm_of(
+        ["she", "her", "her", "hers", "herself"],
+        Person::Third,
+        Gender::Feminine,
+        Number::Singular,
+        Dialect::Modern,
+    ),
+    paradigm_of(
+        ["it", "it", "its", "its", "itself"],
+        Person::Third,
+        Gender::Neutral,
+        Number::Singular,
+        Dialect::Modern,
+    ),
+    paradigm_of(
+        ["they", "them", "their", "theirs", "themselves"],
+        Person::Third,
+        Gender::Neutral,
+        Number::Plural,
+        Dialect::Modern,
+    ),
+    paradigm_of(
+        ["one", "one", "one's", "one's", "oneself"],
+        Person::Third,
+        Gender::Neutral,
+        Number::Singular,
+        Dialect::Modern,
+    ),
+];
+
+/// The paradigm `form` belongs to, whichever of its forms it is:
+/// `thee` and `thine` both find `thou`.
+pub fn paradigm(form: &str) -> Option<&'static Paradigm> {
+    PARADIGMS.iter().find(|paradigm| paradigm.contains(form))
+}
+
+/// The independent possessive going with `possessive`: `mine` for `my`
+/// from the table, otherwise `possessive` with `-s` added unless it
+/// already ends in one, as in `xyrs` and `its`.
+pub fn independent_possessive(possessive: &str) -> String {
+    let found = PARADIGMS
+        .iter()
+        .find(|paradigm| paradigm.possessive.eq_ignore_ascii_case(possessive));
+    match found {
+        Some(paradigm) => paradigm.independent_possessive.to_string(),
+        None if possessive.ends_with('s') => possessive.to_string(),
+        None => format!("{}s", possessive),
+    }
+}
+
+/// The paradigm for a person, gender and number. Early Modern English
+/// falls back to the modern pronouns outside the second person.
+pub fn paradigm_for(
+    person: Person,
+    gender: Gender,
+    number: Number,
+    dialect: Dialect,
+) -> Option<&'static Paradigm> {
+    let find = |dialect| {
+        let mut agreeing = PARADIGMS.iter().filter(move |paradigm| {
+            paradigm.person == person && paradigm.number == number && paradigm.dialect == dialect
+        });
+        let first = agreeing.clone().next();
+        agreeing
+            .find(|paradigm| paradigm.gender == gender)
+            .or(first)
+    };
+    find(dialect).or_else(|| find(Dialect::Modern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_paradigm_of_any_form() {
+        let thou = paradigm("thee").unwrap();
+        assert_eq!(thou.forms(), ["thou", "thee", "thy", "thine", "thyself"]);
+        assert_eq!(thou.person, Person::Second);
+        assert_eq!(thou.dialect, Dialect::EarlyModern);
+        assert_eq!(paradigm("Thine"), Some(thou));
+        assert_eq!(paradigm("ye").unwrap().number, Number::Plural);
+        assert_eq!(paradigm("yourselves").unwrap().number, Number::Plural);
+        assert_eq!(paradigm("you").unwrap().number, Number::Singular);
+        assert_eq!(paradigm("me").unwrap().subject, "I");
+        assert_eq!(paradigm("hers").unwrap().gender, Gender::Feminine);
+        assert_eq!(paradigm("them").unwrap().reflexive, "themselves");
+        assert_eq!(paradigm("who"), None);
+        assert_eq!(PronounForms::of("thy").reflexive, "thyself");
+        assert_eq!(PronounForms::of("who").object, "who");
+        assert_eq!(independent_possessive("her"), "hers");
+        assert_eq!(independent_possessive("xyr"), "xyrs");
+    }
+
+    #[test]
+    fn finds_the_paradigm_for_features() {
+        let find = |person, gender, number, dialect| {
+            paradigm_for(person, gender, number, dialect).map(|paradigm| paradigm.subject)
+        };
+        assert_eq!(
+            find(
+                Person::Third,
+                Gender::Feminine,
+                Number::Singular,
+                Dialect::Modern
+            ),
+            Some("she")
+        );
+        assert_eq!(
+            find(
+                Person::Second,
+                Gender::Masculine,
+                Number::Singular,
+                Dialect::EarlyModern
+            ),
+            Some("thou")
+        );
+        assert_eq!(
+            find(
+                Person::Third,
+                Gender::Masculine,
+                Number::Plural,
+                Dialect::Modern
+            ),
+            Some("they")
+        );
+        assert_eq!(
+            find(
+                Person::Third,
+                Gender::Masculine,
+                Number::Singular,
+                Dialect::EarlyModern
+            ),
+            Some("he")
+        );
+    }
+}
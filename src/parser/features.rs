@@ -0,0 +1,219 @@
+//! Features of closed-class words, looked up by spelling.
+//!
+//! The type and category of a conjunction, the sentiment of an
+//! interjection, the case a preposition takes and what an adverb modifies
+//! cannot be read off the word the way the degree of an adjective can, so
+//! they come from these tables. Words not listed get the defaults of
+//! their feature structs.
+
+use super::word_enums::{
+    AdverbFeatures, ConjunctionCategory, ConjunctionFeatures, ConjunctionType,
+    InterjectionFeatures, Modifies, Position, PrepositionCase, PrepositionFeatures, Sentiment,
+};
+
+/// Coordinating conjunctions with their category.
+const COORDINATING: [(&str, ConjunctionCategory); 7] = [
+    ("and", ConjunctionCategory::Addition),
+    ("nor", ConjunctionCategory::Addition),
+    ("or", ConjunctionCategory::Substitution),
+    ("but", ConjunctionCategory::ContrastDisagreement),
+    ("yet", ConjunctionCategory::ContrastDisagreement),
+    ("so", ConjunctionCategory::Causal),
+    ("for", ConjunctionCategory::Causal),
+];
+
+/// The first words of correlative conjunctions, as in `both ... and`.
+const CORRELATIVE: [(&str, ConjunctionCategory); 4] = [
+    ("both", ConjunctionCategory::Addition),
+    ("neither", ConjunctionCategory::Addition),
+    ("either", ConjunctionCategory::Substitution),
+    ("whether", ConjunctionCategory::Conditional),
+];
+
+/// Subordinating conjunctions, including the Early Modern `an` (if) and
+/// `ere` (before).
+const SUBORDINATING: [(&str, ConjunctionCategory); 29] = [
+    ("as", ConjunctionCategory::ComparisonAgreement),
+    ("than", ConjunctionCategory::ComparisonAgreement),
+    ("although", ConjunctionCategory::ContrastDisagreement),
+    ("though", ConjunctionCategory::ContrastDisagreement),
+    ("whereas", ConjunctionCategory::ContrastDisagreement),
+    ("because", ConjunctionCategory::Causal),
+    ("cause", ConjunctionCategory::Causal),
+    ("since", ConjunctionCategory::Causal),
+    ("that", ConjunctionCategory::Causal),
+    ("whereby", ConjunctionCategory::Causal),
+    ("if", ConjunctionCategory::Conditional),
+    ("an", ConjunctionCategory::Conditional),
+    ("unless", ConjunctionCategory::Conditional),
+    ("lest", ConjunctionCategory::Conditional),
+    ("after", ConjunctionCategory::Temporal),
+    ("before", ConjunctionCategory::Temporal),
+    ("ere", ConjunctionCategory::Temporal),
+    ("now", ConjunctionCategory::Temporal),
+    ("once", ConjunctionCategory::Temporal),
+    ("till", ConjunctionCategory::Temporal),
+    ("until", ConjunctionCategory::Temporal),
+    ("when", ConjunctionCategory::Temporal),
+    ("whenever", ConjunctionCategory::Temporal),
+    ("while", ConjunctionCategory::Temporal),
+    ("whilst", ConjunctionCategory::Temporal),
+    ("whereupon", ConjunctionCategory::Temporal),
+    ("where", ConjunctionCategory::Addition),
+    ("wherein", ConjunctionCategory::Addition),
+    ("wherever", ConjunctionCategory::Addition),
+];
+
+/// Interjections that are not neutral.
+const INTERJECTIONS: [(&str, Sentiment); 30] = [
+    ("ahoy", Sentiment::Excitement),
+    ("ha", Sentiment::Excitement),
+    ("heigh", Sentiment::Excitement),
+    ("hey", Sentiment::Excitement),
+    ("heyday", Sentiment::Excitement),
+    ("ho", Sentiment::Excitement),
+    ("holla", Sentiment::Excitement),
+    ("hurrah", Sentiment::Excitement),
+    ("'zounds", Sentiment::Excitement),
+    ("zounds", Sentiment::Excitement),
+    ("alack", Sentiment::Pain),
+    ("alas", Sentiment::Pain),
+    ("ouch", Sentiment::Pain),
+    ("ah", Sentiment::Surprise),
+    ("lo", Sentiment::Surprise),
+    ("marry", Sentiment::Surprise),
+    ("oh", Sentiment::Surprise),
+    ("what", Sentiment::Surprise),
+    ("why", Sentiment::Surprise),
+    ("fie", Sentiment::Disapproval),
+    ("nay", Sentiment::Disapproval),
+    ("no", Sentiment::Disapproval),
+    ("pish", Sentiment::Disapproval),
+    ("tush", Sentiment::Disapproval),
+    ("tut", Sentiment::Disapproval),
+    ("amen", Sentiment::Approval),
+    ("ay", Sentiment::Approval),
+    ("aye", Sentiment::Approval),
+    ("gramercy", Sentiment::Approval),
+    ("yes", Sentiment::Approval),
+];
+
+/// Prepositions that do not take the accusative: `of` marks possession,
+/// `to` and `unto` the recipient, and `than` and `as` traditionally take
+/// the nominative, as in `taller than I`.
+const PREPOSITIONS: [(&str, PrepositionCase); 5] = [
+    ("of", PrepositionCase::Genitive),
+    ("to", PrepositionCase::Dative),
+    ("unto", PrepositionCase::Dative),
+    ("than", PrepositionCase::Nominative),
+    ("as", PrepositionCase::Nominative),
+];
+
+/// Adverbs that do not follow the verb they modify: adverbs of degree
+/// before adjectives and adverbs, and adverbs of frequency and certainty
+/// before the verb.
+const ADVERBS: [(&str, Modifies, Position); 30] = [
+    ("very", Modifies::Adjective, Position::Before),
+    ("too", Modifies::Adjective, Position::Before),
+    ("so", Modifies::Adjective, Position::Before),
+    ("quite", Modifies::Adjective, Position::Before),
+    ("rather", Modifies::Adjective, Position::Before),
+    ("more", Modifies::Adjective, Position::Before),
+    ("most", Modifies::Adjective, Position::Before),
+    ("less", Modifies::Adjective, Position::Before),
+    ("least", Modifies::Adjective, Position::Before),
+    ("almost", Modifies::Adjective, Position::Before),
+    ("half", Modifies::Adjective, Position::Before),
+    ("enough", Modifies::Adjective, Position::After),
+    ("never", Modifies::Verb, Position::Before),
+    ("ne'er", Modifies::Verb, Position::Before),
+    ("ever", Modifies::Verb, Position::Before),
+    ("e'er", Modifies::Verb, Position::Before),
+    ("always", Modifies::Verb, Position::Before),
+    ("often", Modifies::Verb, Position::Before),
+    ("seldom", Modifies::Verb, Position::Before),
+    ("sometimes", Modifies::Verb, Position::Before),
+    ("already", Modifies::Verb, Position::Before),
+    ("also", Modifies::Verb, Position::Before),
+    ("just", Modifies::Verb, Position::Before),
+    ("surely", Modifies::Verb, Position::Before),
+    ("perhaps", Modifies::Verb, Position::Before),
+    ("perchance", Modifies::Verb, Position::Before),
+    ("haply", Modifies::Verb, Position::Before),
+    ("belike", Modifies::Verb, Position::Before),
+    ("even", Modifies::Verb, Position::Before),
+    ("only", Modifies::Verb, Position::Before),
+];
+
+pub fn conjunction_features(word: &str) -> ConjunctionFeatures {
+    let word = word.to_lowercase();
+    let types = [
+        (ConjunctionType::Coordinating, &COORDINATING[..]),
+        (ConjunctionType::Correlative, &CORRELATIVE[..]),
+        (ConjunctionType::Subordinating, &SUBORDINATING[..]),
+    ];
+    types
+        .into_iter()
+        .find_map(|(conjunction_type, conjunctions)| {
+            conjunctions
+                .iter()
+                .find(|(conjunction, _)| *conjunction == word)
+                .map(|&(_, category)| ConjunctionFeatures {
+                    conjunction_type,
+                    category,
+                })
+        })
+        .unwrap_or_default()
+}
+
+pub fn interjection_features(word: &str) -> InterjectionFeatures {
+    let word = word.to_lowercase();
+    INTERJECTIONS
+        .iter()
+        .find(|(interjection, _)| *interjection == word)
+        .map(|&(_, sentiment)| InterjectionFeatures { sentiment })
+        .unwrap_or_default()
+}
+
+pub fn preposition_features(word: &str) -> PrepositionFeatures {
+    let word = word.to_lowercase();
+    PREPOSITIONS
+        .iter()
+        .find(|(preposition, _)| *preposition == word)
+        .map(|&(_, case)| PrepositionFeatures { case })
+        .unwrap_or_default()
+}
+
+pub fn adverb_features(word: &str) -> AdverbFeatures {
+    let word = word.to_lowercase();
+    ADVERBS
+        .iter()
+        .find(|(adverb, _, _)| *adverb == word)
+        .map(|&(_, modifies, position)| AdverbFeatures { modifies, position })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn looks_up_closed_class_features() {
+        let because = conjunction_features("Because");
+        assert_eq!(because.conjunction_type, ConjunctionType::Subordinating);
+        assert_eq!(because.category, ConjunctionCategory::Causal);
+        assert_eq!(
+            conjunction_features("or").category,
+            ConjunctionCategory::Substitution
+        );
+        assert_eq!(interjection_features("alas").sentiment, Sentiment::Pain);
+        assert_eq!(interjection_features("adieu").sentiment, Sentiment::Neutral);
+        assert_eq!(preposition_features("of").case, PrepositionCase::Genitive);
+        assert_eq!(
+            preposition_features("with").case,
+            PrepositionCase::Accusative
+        );
+        assert_eq!(adverb_features("very").modifies, Modifies::Adjective);
+        assert_eq!(adverb_features("quickly"), AdverbFeatures::default());
+    }
+}
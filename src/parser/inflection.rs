@@ -0,0 +1,382 @@
+//! Rule-based English noun inflection.
+//!
+//! `pluralize` and `singularize` try, in order, the irregular and
+//! invariant tables, the Latin and Greek plurals, the `-f`/`-fe` and `-o`
+//! nouns that take `-ves` and `-oes`, and then the regular spelling rules:
+//! `-es` after sibilants, `-ies` after a consonant and `y`, and `-s`
+//! otherwise. Both keep the capitalisation of the word they are given.
+
+/// Singular and plural forms that follow no rule.
+const IRREGULAR: [(&str, &str); 14] = [
+    ("man", "men"),
+    ("woman", "women"),
+    ("child", "children"),
+    ("mouse", "mice"),
+    ("louse", "lice"),
+    ("goose", "geese"),
+    ("foot", "feet"),
+    ("tooth", "teeth"),
+    ("ox", "oxen"),
+    ("person", "people"),
+    ("die", "dice"),
+    ("penny", "pence"),
+    ("quiz", "quizzes"),
+    ("whiz", "whizzes"),
+];
+
+/// Older plurals that are still recognised but never generated.
+const ARCHAIC: [(&str, &str); 5] = [
+    ("brother", "brethren"),
+    ("cow", "kine"),
+    ("eye", "eyne"),
+    ("shoe", "shoon"),
+    ("hose", "hosen"),
+];
+
+/// Irregular nouns whose compounds, such as `fisherman` and
+/// `grandchild`, inflect the same way.
+const COMPOUNDING: [&str; 4] = ["man", "woman", "child", "person"];
+
+/// Words ending in `man` or `men` that are not compounds of them.
+const NOT_COMPOUNDS: [&str; 18] = [
+    "human", "german", "talisman", "shaman", "caiman", "ottoman", "roman", "norman", "walkman",
+    "omen", "semen", "specimen", "abdomen", "regimen", "stamen", "acumen", "hymen", "amen",
+];
+
+/// Nouns whose plural is the same as their singular, and mass nouns with
+/// no plural.
+const INVARIANT: [&str; 30] = [
+    "sheep",
+    "deer",
+    "fish",
+    "swine",
+    "moose",
+    "salmon",
+    "trout",
+    "cod",
+    "bison",
+    "aircraft",
+    "spacecraft",
+    "offspring",
+    "series",
+    "species",
+    "means",
+    "news",
+    "headquarters",
+    "corps",
+    "information",
+    "equipment",
+    "rice",
+    "money",
+    "advice",
+    "furniture",
+    "luggage",
+    "knowledge",
+    "wheat",
+    "music",
+    "courage",
+    "counsel",
+];
+
+const CLASSICAL: [(&str, &str); 33] = [
+    ("cactus", "cacti"),
+    ("focus", "foci"),
+    ("fungus", "fungi"),
+    ("nucleus", "nuclei"),
+    ("radius", "radii"),
+    ("stimulus", "stimuli"),
+    ("alumnus", "alumni"),
+    ("syllabus", "syllabi"),
+    ("phenomenon", "phenomena"),
+    ("criterion", "criteria"),
+    ("datum", "data"),
+    ("medium", "media"),
+    ("curriculum", "curricula"),
+    ("memorandum", "memoranda"),
+    ("bacterium", "bacteria"),
+    ("stratum", "strata"),
+    ("erratum", "errata"),
+    ("appendix", "appendices"),
+    ("index", "indices"),
+    ("matrix", "matrices"),
+    ("vertex", "vertices"),
+    ("apex", "apices"),
+    ("alga", "algae"),
+    ("larva", "larvae"),
+    ("vertebra", "vertebrae"),
+    ("antenna", "antennae"),
+    ("nebula", "nebulae"),
+    ("genus", "genera"),
+    ("corpus", "corpora"),
+    ("opus", "opera"),
+    ("cherub", "cherubim"),
+    ("seraph", "seraphim"),
+    ("automaton", "automata"),
+];
+
+/// Greek nouns in `-sis`, which become `-ses`.
+const SIS: [&str; 12] = [
+    "analysis",
+    "axis",
+    "basis",
+    "crisis",
+    "diagnosis",
+    "ellipsis",
+    "emphasis",
+    "hypothesis",
+    "oasis",
+    "parenthesis",
+    "synopsis",
+    "thesis",
+];
+
+/// Nouns in `-f` or `-fe` that take `-ves`; others, like `roof` and
+/// `chief`, take `-s`.
+const VES: [&str; 14] = [
+    "calf", "elf", "half", "knife", "leaf", "life", "loaf", "self", "sheaf", "shelf", "thief",
+    "wife", "wolf", "scarf",
+];
+
+/// Nouns in `-o` that take `-es`; others, like `piano`, take `-s`.
+const OES: [&str; 11] = [
+    "buffalo", "domino", "echo", "embargo", "hero", "mosquito", "potato", "tomato", "torpedo",
+    "veto", "volcano",
+];
+
+/// Singular nouns in a single `-s`, which take `-es`.
+const SINGULAR_S: [&str; 18] = [
+    "alias", "atlas", "bias", "bonus", "bus", "campus", "canvas", "census", "chorus", "circus",
+    "gas", "iris", "lens", "octopus", "status", "thomas", "virus", "walrus",
+];
+
+/// Nouns in `-ie`, whose plurals look like those of nouns in `-y`.
+const IE: [&str; 14] = [
+    "calorie", "cookie", "genie", "lie", "movie", "pie", "prairie", "rookie", "smoothie", "tie",
+    "zombie", "sortie", "brownie", "eerie",
+];
+
+/// Nouns in `-ch` pronounced `k` or ending in `-che`, which take `-s`.
+const CH_S: [&str; 9] = [
+    "stomach",
+    "monarch",
+    "epoch",
+    "patriarch",
+    "matriarch",
+    "ache",
+    "cache",
+    "niche",
+    "avalanche",
+];
+
+pub fn pluralize(word: &str) -> String {
+    let lowercase = word.to_lowercase();
+    match_case(word, &plural(&lowercase))
+}
+
+pub fn singularize(word: &str) -> String {
+    let lowercase = word.to_lowercase();
+    match_case(word, &singular(&lowercase))
+}
+
+fn plural(word: &str) -> String {
+    if word.is_empty() || INVARIANT.contains(&word) {
+        return word.to_string();
+    }
+    if let Some(plural) = irregular(word, true) {
+        return plural;
+    }
+    if let Some(&(_, plural)) = CLASSICAL.iter().find(|(singular, _)| *singular == word) {
+        return plural.to_string();
+    }
+    if SIS.contains(&word) {
+        return format!("{}es", &word[..word.len() - 2]);
+    }
+    if VES.contains(&word) {
+        let stem = word.trim_end_matches('e').trim_end_matches('f');
+        return format!("{}ves", stem);
+    }
+    if OES.contains(&word) || CH_S.contains(&word) {
+        return match word.ends_with('o') {
+            true => format!("{}es", word),
+            false => format!("{}s", word),
+        };
+    }
+
+    let mut chars = word.chars().rev();
+    let (last, before) = (chars.next(), chars.next());
+    if last == Some('y') && before.is_some_and(|c| !is_vowel(c)) {
+        format!("{}ies", &word[..word.len() - 1])
+    } else if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|ending| word.ends_with(ending))
+    {
+        format!("{}es", word)
+    } else {
+        format!("{}s", word)
+    }
+}
+
+fn singular(word: &str) -> String {
+    if word.is_empty() || INVARIANT.contains(&word) {
+        return word.to_string();
+    }
+    if let Some(singular) = irregular(word, false) {
+        return singular;
+    }
+    if let Some(&(singular, _)) = ARCHAIC.iter().find(|(_, plural)| *plural == word) {
+        return singular.to_string();
+    }
+    if let Some(&(singular, _)) = CLASSICAL.iter().find(|(_, plural)| *plural == word) {
+        return singular.to_string();
+    }
+    if let Some(stem) = word.strip_suffix("es") {
+        if SIS
+            .iter()
+            .any(|singular| singular.strip_suffix("is") == Some(stem))
+        {
+            return format!("{}is", stem);
+        }
+    }
+    if let Some(stem) = word.strip_suffix("ves") {
+        let found = VES
+            .iter()
+            .find(|singular| singular.trim_end_matches('e').trim_end_matches('f') == stem);
+        if let Some(singular) = found {
+            return singular.to_string();
+        }
+    }
+    if let Some(stem) = word.strip_suffix("ies") {
+        let ie = format!("{}ie", stem);
+        return match IE.contains(&ie.as_str()) || stem.chars().count() < 2 {
+            true => ie,
+            false => format!("{}y", stem),
+        };
+    }
+    if let Some(stem) = word.strip_suffix("es") {
+        let takes_es = OES.contains(&stem)
+            || SINGULAR_S.contains(&stem)
+            || stem.ends_with("ss")
+            || stem.ends_with("zz")
+            || stem.ends_with("tz")
+            || stem.ends_with('x')
+            || (stem.ends_with("sh") || stem.ends_with("ch"))
+                && !CH_S.contains(&word.trim_end_matches('s'));
+        if takes_es {
+            return stem.to_string();
+        }
+    }
+    let looks_singular = ["ss", "us", "is"]
+        .iter()
+        .any(|ending| word.ends_with(ending))
+        || SINGULAR_S.contains(&word);
+    match word.strip_suffix('s') {
+        Some(stem) if !looks_singular && !stem.is_empty() => stem.to_string(),
+        _ => word.to_string(),
+    }
+}
+
+/// Looks `word` up in the irregular table, whole or as the end of a
+/// compound, and returns its plural or its singular.
+fn irregular(word: &str, to_plural: bool) -> Option<String> {
+    let exception = NOT_COMPOUNDS
+        .iter()
+        .any(|exception| word.strip_suffix('s').unwrap_or(word) == *exception);
+    if exception {
+        return None;
+    }
+    IRREGULAR.iter().find_map(|&(singular, plural)| {
+        let (from, to) = match to_plural {
+            true => (singular, plural),
+            false => (plural, singular),
+        };
+        let head = word.strip_suffix(from)?;
+        (head.is_empty() || COMPOUNDING.contains(&singular)).then(|| format!("{}{}", head, to))
+    })
+}
+
+fn is_vowel(c: char) -> bool {
+    "aeiou".contains(c)
+}
+
+/// `inflected` with the capitalisation of `original`: all capitals, a
+/// leading capital, or as it is.
+pub(crate) fn match_case(original: &str, inflected: &str) -> String {
+    let mut letters = original.chars().filter(|c| c.is_alphabetic());
+    match letters.next() {
+        Some(first) if first.is_uppercase() => {
+            let rest: Vec<char> = letters.collect();
+            if !rest.is_empty() && rest.iter().all(|c| c.is_uppercase()) {
+                inflected.to_uppercase()
+            } else {
+                let mut chars = inflected.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+        }
+        _ => inflected.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAIRS: [(&str, &str); 34] = [
+        ("household", "households"),
+        ("church", "churches"),
+        ("wish", "wishes"),
+        ("box", "boxes"),
+        ("kiss", "kisses"),
+        ("buzz", "buzzes"),
+        ("waltz", "waltzes"),
+        ("bus", "buses"),
+        ("lady", "ladies"),
+        ("day", "days"),
+        ("movie", "movies"),
+        ("leaf", "leaves"),
+        ("wife", "wives"),
+        ("roof", "roofs"),
+        ("glove", "gloves"),
+        ("hero", "heroes"),
+        ("piano", "pianos"),
+        ("foe", "foes"),
+        ("house", "houses"),
+        ("prize", "prizes"),
+        ("stomach", "stomachs"),
+        ("man", "men"),
+        ("fisherman", "fishermen"),
+        ("human", "humans"),
+        ("child", "children"),
+        ("grandchild", "grandchildren"),
+        ("mouse", "mice"),
+        ("quiz", "quizzes"),
+        ("sheep", "sheep"),
+        ("cactus", "cacti"),
+        ("criterion", "criteria"),
+        ("crisis", "crises"),
+        ("appendix", "appendices"),
+        ("larva", "larvae"),
+    ];
+
+    #[test]
+    fn pluralizes_and_singularizes() {
+        for (singular, plural) in PAIRS {
+            assert_eq!(pluralize(singular), plural, "plural of {}", singular);
+            assert_eq!(singularize(plural), singular, "singular of {}", plural);
+        }
+    }
+
+    #[test]
+    fn keeps_singulars_and_case() {
+        assert_eq!(singularize("dignity"), "dignity");
+        assert_eq!(singularize("glass"), "glass");
+        assert_eq!(singularize("status"), "status");
+        assert_eq!(singularize("analysis"), "analysis");
+        assert_eq!(singularize("brethren"), "brother");
+        assert_eq!(pluralize("Montague"), "Montagues");
+        assert_eq!(pluralize("GENTLEMAN"), "GENTLEMEN");
+        assert_eq!(singularize("Ladies"), "Lady");
+    }
+}
@@ -1,34 +1,64 @@
 use std::fmt::Display;
-use super::word_enums::{Gender, Number, Degree, Modifies, Position, PrepositionCase, ConjunctionType, ConjunctionCategory, Sentiment};
+use super::comparison;
+use super::conjugation;
+use super::declension::{self, Paradigm, PronounForms};
+use super::features;
+use super::inflection;
+use super::tagset::Tag;
+use super::word_enums::{Gender, Number, Degree, Modifies, Position, PrepositionCase, ConjunctionType, ConjunctionCategory, Sentiment, VerbFeatures, Person, Dialect, PronounFeatures, AdjectiveFeatures, AdverbFeatures, PrepositionFeatures, ConjunctionFeatures, InterjectionFeatures, ArticleFeatures};
 
 
+#[derive(Debug, Clone, PartialEq)]
 pub enum PartOfSpeech {
     Noun(Word),
-    Pronoun(Word),
+    Pronoun(Word, PronounForms, PronounFeatures),
     Verb(Word),
-    Adjective(Word),
-    Adverb(Word),
-    Preposition(Word),
-    Conjunction(Word),
-    Interjection(Word),
-    Article(Word),
+    Adjective(Word, AdjectiveFeatures),
+    Adverb(Word, AdverbFeatures),
+    Preposition(Word, PrepositionFeatures),
+    Conjunction(Word, ConjunctionFeatures),
+    Interjection(Word, InterjectionFeatures),
+    Article(Word, ArticleFeatures),
+    Determiner(Word),
+    Numeral(Word),
+    Particle(Word),
+    Auxiliary(Word),
+    Modal(Word),
+    Punctuation(Word),
 }
 
+/// A word as it appeared in the text, with the base form its other forms
+/// are built from: `households` with `household`. Displays as it appeared.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Word {
     base_form: String,
+    surface_form: String,
 }
 
 impl Word {
-    fn new(base_form: String) -> Word {
+    /// A word that appeared in its base form.
+    pub fn new(base_form: String) -> Word {
         Word {
-            base_form: base_form,
+            surface_form: base_form.clone(),
+            base_form,
         }
     }
+
+    pub fn with_surface_form(base_form: String, surface_form: String) -> Word {
+        Word {
+            base_form,
+            surface_form,
+        }
+    }
+
+    pub fn surface_form(&self) -> &str {
+        &self.surface_form
+    }
 }
 
 impl Display for Word {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "word") // <-- This needs to be changed to display the word
+        write!(f, "{}", self.surface_form)
     }
 }
 
@@ -40,17 +70,15 @@ pub trait Noun {
 
 impl Noun for Word {
     fn new(singular: &str) -> Word {
-        Word {
-            base_form: singular.to_string(),
-        }
+        Word::new(singular.to_string())
     }
 
     fn pluralize(&self) -> String {
-        format!("{}s", self)
+        inflection::pluralize(&self.base_form)
     }
 
     fn singularize(&self) -> String {
-        self.to_string()
+        inflection::singularize(&self.base_form)
     }
 }
 
@@ -77,100 +105,114 @@ impl Noun for PartOfSpeech {
 
 
 pub trait Pronoun {
-    fn new(subject_form: &str, object_form: &str, possessive_form: &str, reflexive_form: &str, gender: Gender, number: Number) -> Self;
+    fn new(subject_form: &str, object_form: &str, possessive_form: &str, reflexive_form: &str, person: Person, gender: Gender, number: Number) -> Self;
+    fn from_form(form: &str) -> Self;
     fn subject_form(&self) -> String;
     fn object_form(&self) -> String;
     fn possessive_form(&self) -> String;
+    fn independent_possessive_form(&self) -> String;
     fn reflexive_form(&self) -> String;
+    fn pronoun_person(&self) -> Person;
     fn pronoun_gender(&self) -> Gender;
     fn pronoun_number(&self) -> Number;
 }
 
-impl Pronoun for Word {
-    fn new(subject_form: &str, object_form: &str, possessive_form: &str, reflexive_form: &str, pronoun_gender: Gender, number: Number) -> Word {
-        Word {
-            base_form: subject_form.to_string(),
-        }
-    }
-
-    fn subject_form(&self) -> String {
-        self.to_string()
-    }
-
-    fn object_form(&self) -> String {
-        self.to_string()
-    }
-
-    fn possessive_form(&self) -> String {
-        self.to_string()
-    }
-
-    fn reflexive_form(&self) -> String {
-        self.to_string()
-    }
-
-    fn pronoun_gender(&self) -> Gender {
-        Gender::Neutral
-    }
-
-    fn pronoun_number(&self) -> Number {
-        Number::Singular
+/// The person, gender and number of a personal pronoun, or the defaults
+/// for one outside the table.
+fn pronoun_features(paradigm: Option<&Paradigm>) -> PronounFeatures {
+    match paradigm {
+        Some(paradigm) => PronounFeatures {
+            person: paradigm.person,
+            gender: paradigm.gender,
+            number: paradigm.number,
+        },
+        None => PronounFeatures::default(),
     }
 }
 
 impl Pronoun for PartOfSpeech {
-    fn new(subject_form: &str, object_form: &str, possessive_form: &str, reflexive_form: &str, gender: Gender, number: Number) -> PartOfSpeech {
-        PartOfSpeech::Pronoun(Word::new(subject_form.to_string()))
+    /// A pronoun with the given forms, which need not be in the table; its
+    /// independent possessive is taken from the table or adds `-s`.
+    fn new(subject_form: &str, object_form: &str, possessive_form: &str, reflexive_form: &str, person: Person, gender: Gender, number: Number) -> PartOfSpeech {
+        let forms = PronounForms {
+            subject: subject_form.to_string(),
+            object: object_form.to_string(),
+            possessive: possessive_form.to_string(),
+            independent_possessive: declension::independent_possessive(possessive_form),
+            reflexive: reflexive_form.to_string(),
+        };
+        let features = PronounFeatures { person, gender, number };
+        PartOfSpeech::Pronoun(Word::new(subject_form.to_string()), forms, features)
+    }
+
+    fn from_form(form: &str) -> PartOfSpeech {
+        let forms = PronounForms::of(form);
+        let features = pronoun_features(declension::paradigm(form));
+        let word = Word::with_surface_form(forms.subject.clone(), form.to_string());
+        PartOfSpeech::Pronoun(word, forms, features)
     }
 
     fn subject_form(&self) -> String {
         match self {
-            PartOfSpeech::Pronoun(word) => word.subject_form(),
+            PartOfSpeech::Pronoun(_, forms, _) => forms.subject.clone(),
             _ => "".to_string(),
         }
     }
 
     fn object_form(&self) -> String {
         match self {
-            PartOfSpeech::Pronoun(word) => word.object_form(),
+            PartOfSpeech::Pronoun(_, forms, _) => forms.object.clone(),
             _ => "".to_string(),
         }
     }
 
     fn possessive_form(&self) -> String {
         match self {
-            PartOfSpeech::Pronoun(word) => word.possessive_form(),
+            PartOfSpeech::Pronoun(_, forms, _) => forms.possessive.clone(),
+            _ => "".to_string(),
+        }
+    }
+
+    fn independent_possessive_form(&self) -> String {
+        match self {
+            PartOfSpeech::Pronoun(_, forms, _) => forms.independent_possessive.clone(),
             _ => "".to_string(),
         }
     }
 
     fn reflexive_form(&self) -> String {
         match self {
-            PartOfSpeech::Pronoun(word) => word.reflexive_form(),
+            PartOfSpeech::Pronoun(_, forms, _) => forms.reflexive.clone(),
             _ => "".to_string(),
         }
     }
 
+    fn pronoun_person(&self) -> Person {
+        match self {
+            PartOfSpeech::Pronoun(_, _, features) => features.person,
+            _ => Person::Third,
+        }
+    }
+
     fn pronoun_gender(&self) -> Gender {
         match self {
-            PartOfSpeech::Pronoun(word) => word.pronoun_gender(),
+            PartOfSpeech::Pronoun(_, _, features) => features.gender,
             _ => Gender::Neutral,
         }
     }
 
     fn pronoun_number(&self) -> Number {
         match self {
-            PartOfSpeech::Pronoun(word) => word.pronoun_number(),
+            PartOfSpeech::Pronoun(_, _, features) => features.number,
             _ => Number::Singular,
             // other cases
         }
     }
 }
 
-
 pub trait Verb {
     fn new(base_form: &str) -> Self;
-    fn conjugate(&self, tense: &str) -> String;
+    fn conjugate(&self, features: &VerbFeatures) -> String;
     fn verb_base_form(&self) -> String;
     fn past_tense(&self) -> String;
     fn past_participle(&self) -> String;
@@ -184,59 +226,47 @@ pub trait Verb {
 
 impl Verb for Word {
     fn new(base_form: &str) -> Word {
-        Word {
-            base_form: base_form.to_string(),
-        }
+        Word::new(base_form.to_string())
     }
 
-    fn conjugate(&self, tense: &str) -> String {
-        match tense {
-            "past" => self.past_tense(),
-            "past_participle" => self.past_participle(),
-            "present" => self.present_tense(),
-            "present_participle" => self.present_participle(),
-            "third_person_singular" => self.third_person_singular(),
-            "present_tense_singular" => self.present_tense_singular(),
-            "present_tense_plural" => self.present_tense_plural(),
-            "infinitive" => self.infinitive(),
-            _ => self.verb_base_form(),
-        }
+    fn conjugate(&self, features: &VerbFeatures) -> String {
+        conjugation::conjugate(&self.base_form, features)
     }
 
     fn verb_base_form(&self) -> String {
-        self.to_string()
+        self.base_form.clone()
     }
 
     fn past_tense(&self) -> String {
-        format!("{}ed", self)
+        conjugation::past_tense(&self.base_form)
     }
 
     fn past_participle(&self) -> String {
-        format!("{}ed", self)
+        conjugation::past_participle(&self.base_form)
     }
 
     fn present_tense(&self) -> String {
-        format!("{}s", self)
+        conjugation::third_person_singular(&self.base_form)
     }
 
     fn present_participle(&self) -> String {
-        format!("{}ing", self)
+        conjugation::present_participle(&self.base_form)
     }
 
     fn third_person_singular(&self) -> String {
-        format!("{}s", self)
+        conjugation::third_person_singular(&self.base_form)
     }
 
     fn present_tense_singular(&self) -> String {
-        format!("{}s", self)
+        conjugation::third_person_singular(&self.base_form)
     }
 
     fn present_tense_plural(&self) -> String {
-        self.to_string()
+        conjugation::present_plural(&self.base_form)
     }
 
     fn infinitive(&self) -> String {
-        format!("to {}", self)
+        format!("to {}", self.base_form)
     }
 }
 
@@ -245,72 +275,92 @@ impl Verb for PartOfSpeech {
         PartOfSpeech::Verb(Word::new(base_form.to_string()))
     }
 
-    fn conjugate(&self, tense: &str) -> String {
+    fn conjugate(&self, features: &VerbFeatures) -> String {
         match self {
-            PartOfSpeech::Verb(word) => word.conjugate(tense),
+            PartOfSpeech::Verb(word)
+            | PartOfSpeech::Auxiliary(word)
+            | PartOfSpeech::Modal(word) => word.conjugate(features),
             _ => "".to_string(),
         }
     }
 
     fn verb_base_form(&self) -> String {
         match self {
-            PartOfSpeech::Verb(word) => word.verb_base_form(),
+            PartOfSpeech::Verb(word)
+            | PartOfSpeech::Auxiliary(word)
+            | PartOfSpeech::Modal(word) => word.verb_base_form(),
             _ => "".to_string(),
         }
     }
 
     fn past_tense(&self) -> String {
         match self {
-            PartOfSpeech::Verb(word) => word.past_tense(),
+            PartOfSpeech::Verb(word)
+            | PartOfSpeech::Auxiliary(word)
+            | PartOfSpeech::Modal(word) => word.past_tense(),
             _ => "".to_string(),
         }
     }
 
     fn past_participle(&self) -> String {
         match self {
-            PartOfSpeech::Verb(word) => word.past_participle(),
+            PartOfSpeech::Verb(word)
+            | PartOfSpeech::Auxiliary(word)
+            | PartOfSpeech::Modal(word) => word.past_participle(),
             _ => "".to_string(),
         }
     }
 
     fn present_tense(&self) -> String {
         match self {
-            PartOfSpeech::Verb(word) => word.present_tense(),
+            PartOfSpeech::Verb(word)
+            | PartOfSpeech::Auxiliary(word)
+            | PartOfSpeech::Modal(word) => word.present_tense(),
             _ => "".to_string(),
         }
     }
 
     fn present_participle(&self) -> String {
         match self {
-            PartOfSpeech::Verb(word) => word.present_participle(),
+            PartOfSpeech::Verb(word)
+            | PartOfSpeech::Auxiliary(word)
+            | PartOfSpeech::Modal(word) => word.present_participle(),
             _ => "".to_string(),
         }
     }
 
     fn third_person_singular(&self) -> String {
         match self {
-            PartOfSpeech::Verb(word) => word.third_person_singular(),
+            PartOfSpeech::Verb(word)
+            | PartOfSpeech::Auxiliary(word)
+            | PartOfSpeech::Modal(word) => word.third_person_singular(),
             _ => "".to_string(),
         }
     }
 
     fn present_tense_singular(&self) -> String {
         match self {
-            PartOfSpeech::Verb(word) => word.present_tense_singular(),
+            PartOfSpeech::Verb(word)
+            | PartOfSpeech::Auxiliary(word)
+            | PartOfSpeech::Modal(word) => word.present_tense_singular(),
             _ => "".to_string(),
         }
     }
 
     fn present_tense_plural(&self) -> String {
         match self {
-            PartOfSpeech::Verb(word) => word.present_tense_plural(),
+            PartOfSpeech::Verb(word)
+            | PartOfSpeech::Auxiliary(word)
+            | PartOfSpeech::Modal(word) => word.present_tense_plural(),
             _ => "".to_string(),
         }
     }
 
     fn infinitive(&self) -> String {
         match self {
-            PartOfSpeech::Verb(word) => word.infinitive(),
+            PartOfSpeech::Verb(word)
+            | PartOfSpeech::Auxiliary(word)
+            | PartOfSpeech::Modal(word) => word.infinitive(),
             _ => "".to_string(),
         }
     }
@@ -327,83 +377,59 @@ pub trait Adjective {
     fn adjective_position(&self) -> Position;
 }
 
-impl Adjective for Word {
-    fn new(base_form: &str, gender: Gender, number: Number, degree: Degree, position: Position) -> Word {
-        Word {
-            base_form: base_form.to_string(),
-        }
-    }
-
-    fn adjust_degree(&self, degree: Degree) -> Word {
-        Word {
-            base_form: self.adjective_base_form(),
-        }
-    }
-
-    fn adjective_base_form(&self) -> String {
-        self.to_string()
-    }
-
-    fn adjective_gender(&self) -> Gender {
-        Gender::Neutral
-    }
-
-    fn adjective_number(&self) -> Number {
-        Number::Singular
-    }
-
-    fn adjective_degree(&self) -> Degree {
-        Degree::Positive
-    }
-
-    fn adjective_position(&self) -> Position {
-        Position::Before
-    }
-}
-
 impl Adjective for PartOfSpeech {
     fn new(base_form: &str, gender: Gender, number: Number, degree: Degree, position: Position) -> PartOfSpeech {
-        PartOfSpeech::Adjective(Word::new(base_form.to_string()))
+        let features = AdjectiveFeatures {
+            gender,
+            number,
+            degree,
+            position,
+        };
+        let word = Word::with_surface_form(base_form.to_string(), comparison::compare(base_form, degree));
+        PartOfSpeech::Adjective(word, features)
     }
 
     fn adjust_degree(&self, degree: Degree) -> PartOfSpeech {
         match self {
-            PartOfSpeech::Adjective(word) => PartOfSpeech::Adjective(word.adjust_degree(degree)),
-            _ => PartOfSpeech::Adjective(Word::new("".to_string())),  
+            PartOfSpeech::Adjective(word, features) => {
+                let word = Word::with_surface_form(word.base_form.clone(), comparison::compare(&word.base_form, degree));
+                PartOfSpeech::Adjective(word, AdjectiveFeatures { degree, ..*features })
+            }
+            _ => PartOfSpeech::Adjective(Word::new("".to_string()), AdjectiveFeatures::default()),
         }
     }
 
     fn adjective_base_form(&self) -> String {
         match self {
-            PartOfSpeech::Adjective(word) => word.base_form(),
+            PartOfSpeech::Adjective(word, _) => word.base_form.clone(),
             _ => "".to_string(),
         }
     }
 
     fn adjective_gender(&self) -> Gender {
         match self {
-            PartOfSpeech::Adjective(word) => word.adjective_gender(),
+            PartOfSpeech::Adjective(_, features) => features.gender,
             _ => Gender::Neutral,
         }
     }
 
     fn adjective_number(&self) -> Number {
         match self {
-            PartOfSpeech::Adjective(word) => word.adjective_number(),
+            PartOfSpeech::Adjective(_, features) => features.number,
             _ => Number::Singular,
         }
     }
 
     fn adjective_degree(&self) -> Degree {
         match self {
-            PartOfSpeech::Adjective(word) => word.adjective_degree(),
+            PartOfSpeech::Adjective(_, features) => features.degree,
             _ => Degree::Positive,
         }
     }
 
     fn adjective_position(&self) -> Position {
         match self {
-            PartOfSpeech::Adjective(word) => word.adjective_position(),
+            PartOfSpeech::Adjective(_, features) => features.position,
             _ => Position::Before,
         }
     }
@@ -417,48 +443,28 @@ pub trait Adverb {
     fn adverb_position(&self) -> Position;
 }
 
-impl Adverb for Word {
-    fn new(base_form: &str, modifies: Modifies, position: Position) -> Word {
-        Word {
-            base_form: base_form.to_string(),
-        }
-    }
-
-    fn adverb_base_form(&self) -> String {
-        self.to_string()
-    }
-
-    fn modifies(&self) -> Modifies {
-        Modifies::Verb
-    }
-
-    fn adverb_position(&self) -> Position {
-        Position::After
-    }
-}
-
 impl Adverb for PartOfSpeech {
     fn new(base_form: &str, modifies: Modifies, position: Position) -> PartOfSpeech {
-        PartOfSpeech::Adverb(Word::new(base_form.to_string()))
+        PartOfSpeech::Adverb(Word::new(base_form.to_string()), AdverbFeatures { modifies, position })
     }
 
     fn adverb_base_form(&self) -> String {
         match self {
-            PartOfSpeech::Adverb(word) => word.base_form(),
+            PartOfSpeech::Adverb(word, _) => word.base_form.clone(),
             _ => "".to_string(),
         }
     }
 
     fn modifies(&self) -> Modifies {
         match self {
-            PartOfSpeech::Adverb(word) => word.modifies(),
+            PartOfSpeech::Adverb(_, features) => features.modifies,
             _ => Modifies::Verb,
         }
     }
 
     fn adverb_position(&self) -> Position {
         match self {
-            PartOfSpeech::Adverb(word) => word.adverb_position(),
+            PartOfSpeech::Adverb(_, features) => features.position,
             _ => Position::After,
         }
     }
@@ -471,37 +477,21 @@ pub trait Preposition {
     fn case(&self) -> PrepositionCase;
 }
 
-impl Preposition for Word {
-    fn new(base_form: &str, case: PrepositionCase) -> Word {
-        Word {
-            base_form: base_form.to_string(),
-        }
-    }
-
-    fn preposition_base_form(&self) -> String {
-        self.to_string()
-    }
-
-    fn case(&self) -> PrepositionCase {
-        PrepositionCase::Accusative
-    }
-}
-
 impl Preposition for PartOfSpeech {
     fn new(base_form: &str, case: PrepositionCase) -> PartOfSpeech {
-        PartOfSpeech::Preposition(Word::new(base_form.to_string()))
+        PartOfSpeech::Preposition(Word::new(base_form.to_string()), PrepositionFeatures { case })
     }
 
     fn preposition_base_form(&self) -> String {
         match self {
-            PartOfSpeech::Preposition(word) => word.preposition_base_form(),
+            PartOfSpeech::Preposition(word, _) => word.base_form.clone(),
             _ => "".to_string(),
         }
     }
 
     fn case(&self) -> PrepositionCase {
         match self {
-            PartOfSpeech::Preposition(word) => word.case(),
+            PartOfSpeech::Preposition(_, features) => features.case,
             _ => PrepositionCase::Accusative,
         }
     }
@@ -515,48 +505,32 @@ pub trait Conjunction {
     fn category(&self) -> ConjunctionCategory;
 }
 
-impl Conjunction for Word {
-    fn new(base_form: &str, conjunction_type: ConjunctionType, category: ConjunctionCategory) -> Word {
-        Word {
-            base_form: base_form.to_string(),
-        }
-    }
-
-    fn base_form(&self) -> String {
-        self.to_string()
-    }
-
-    fn conjunction_type(&self) -> ConjunctionType {
-        ConjunctionType::Coordinating
-    }
-
-    fn category(&self) -> ConjunctionCategory {
-        ConjunctionCategory::Addition
-    }
-}
-
 impl Conjunction for PartOfSpeech {
     fn new(base_form: &str, conjunction_type: ConjunctionType, category: ConjunctionCategory) -> PartOfSpeech {
-        PartOfSpeech::Conjunction(Word::new(base_form.to_string()))
+        let features = ConjunctionFeatures {
+            conjunction_type,
+            category,
+        };
+        PartOfSpeech::Conjunction(Word::new(base_form.to_string()), features)
     }
 
     fn base_form(&self) -> String {
         match self {
-            PartOfSpeech::Conjunction(word) => word.base_form(),
+            PartOfSpeech::Conjunction(word, _) => word.base_form.clone(),
             _ => "".to_string(),
         }
     }
 
     fn conjunction_type(&self) -> ConjunctionType {
         match self {
-            PartOfSpeech::Conjunction(word) => word.conjunction_type(),
+            PartOfSpeech::Conjunction(_, features) => features.conjunction_type,
             _ => ConjunctionType::Coordinating,
         }
     }
 
     fn category(&self) -> ConjunctionCategory {
         match self {
-            PartOfSpeech::Conjunction(word) => word.category(),
+            PartOfSpeech::Conjunction(_, features) => features.category,
             _ => ConjunctionCategory::Addition,
         }
     }
@@ -569,37 +543,21 @@ pub trait Interjection {
     fn sentiment(&self) -> Sentiment;
 }
 
-impl Interjection for Word {
-    fn new(word: &str, sentiment: Sentiment) -> Word {
-        Word {
-            base_form: word.to_string(),
-        }
-    }
-
-    fn word(&self) -> String {
-        self.to_string()
-    }
-
-    fn sentiment(&self) -> Sentiment {
-        Sentiment::Neutral
-    }
-}
-
 impl Interjection for PartOfSpeech {
     fn new(word: &str, sentiment: Sentiment) -> PartOfSpeech {
-        PartOfSpeech::Interjection(Word::new(word.to_string()))
+        PartOfSpeech::Interjection(Word::new(word.to_string()), InterjectionFeatures { sentiment })
     }
 
     fn word(&self) -> String {
         match self {
-            PartOfSpeech::Interjection(word) => word.word(),
+            PartOfSpeech::Interjection(word, _) => word.base_form.clone(),
             _ => "".to_string(),
         }
     }
 
     fn sentiment(&self) -> Sentiment {
         match self {
-            PartOfSpeech::Interjection(word) => word.sentiment(),
+            PartOfSpeech::Interjection(_, features) => features.sentiment,
             _ => Sentiment::Neutral,
         }
     }
@@ -613,49 +571,446 @@ pub trait Article {
     fn article_number(&self) -> Number;
 }
 
-impl Article for Word {
-    fn new(form: &str, gender: Gender, number: Number) -> Word {
-        Word {
-            base_form: form.to_string(),
-        }
-    }
-
-    fn form(&self) -> String {
-        self.to_string()
-    }
-
-    fn article_gender(&self) -> Gender {
-        Gender::Masculine
-    }
-
-    fn article_number(&self) -> Number {
-        Number::Singular
-    }
-}
-
 impl Article for PartOfSpeech {
     fn new(form: &str, gender: Gender, number: Number) -> PartOfSpeech {
-        PartOfSpeech::Article(Word::new(form.to_string()))
+        PartOfSpeech::Article(Word::new(form.to_string()), ArticleFeatures { gender, number })
     }
 
     fn form(&self) -> String {
         match self {
-            PartOfSpeech::Article(word) => word.form(),
+            PartOfSpeech::Article(word, _) => word.base_form.clone(),
             _ => "".to_string(),
         }
     }
 
     fn article_gender(&self) -> Gender {
         match self {
-            PartOfSpeech::Article(word) => word.article_gender(),
+            PartOfSpeech::Article(_, features) => features.gender,
             _ => Gender::Neutral
         }
     }
 
     fn article_number(&self) -> Number {
         match self {
-            PartOfSpeech::Article(word) => word.article_number(),
+            PartOfSpeech::Article(_, features) => features.number,
             _ => Number::Singular
         }
     }
-}
\ No newline at end of file
+}
+
+/// The variant of a `PartOfSpeech` without its word, e.g. for lexicon
+/// entries. Labels follow the Universal Dependencies names where one
+/// exists, except `ART` for articles, `MODAL` for modal verbs and `CONJ`
+/// for conjunctions; `tagset::Tag` converts to the standard tagsets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartOfSpeechKind {
+    Noun,
+    Pronoun,
+    Verb,
+    Adjective,
+    Adverb,
+    Preposition,
+    Conjunction,
+    Interjection,
+    Article,
+    Determiner,
+    Numeral,
+    Particle,
+    Auxiliary,
+    Modal,
+    Punctuation,
+}
+
+impl PartOfSpeechKind {
+    pub const ALL: [PartOfSpeechKind; 15] = [
+        PartOfSpeechKind::Noun,
+        PartOfSpeechKind::Pronoun,
+        PartOfSpeechKind::Verb,
+        PartOfSpeechKind::Adjective,
+        PartOfSpeechKind::Adverb,
+        PartOfSpeechKind::Preposition,
+        PartOfSpeechKind::Conjunction,
+        PartOfSpeechKind::Interjection,
+        PartOfSpeechKind::Article,
+        PartOfSpeechKind::Determiner,
+        PartOfSpeechKind::Numeral,
+        PartOfSpeechKind::Particle,
+        PartOfSpeechKind::Auxiliary,
+        PartOfSpeechKind::Modal,
+        PartOfSpeechKind::Punctuation,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PartOfSpeechKind::Noun => "NOUN",
+            PartOfSpeechKind::Pronoun => "PRON",
+            PartOfSpeechKind::Verb => "VERB",
+            PartOfSpeechKind::Adjective => "ADJ",
+            PartOfSpeechKind::Adverb => "ADV",
+            PartOfSpeechKind::Preposition => "ADP",
+            PartOfSpeechKind::Conjunction => "CONJ",
+            PartOfSpeechKind::Interjection => "INTJ",
+            PartOfSpeechKind::Article => "ART",
+            PartOfSpeechKind::Determiner => "DET",
+            PartOfSpeechKind::Numeral => "NUM",
+            PartOfSpeechKind::Particle => "PART",
+            PartOfSpeechKind::Auxiliary => "AUX",
+            PartOfSpeechKind::Modal => "MODAL",
+            PartOfSpeechKind::Punctuation => "PUNCT",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<PartOfSpeechKind> {
+        PartOfSpeechKind::ALL
+            .into_iter()
+            .find(|kind| kind.label() == label)
+    }
+
+    /// The part of speech of this kind for `word`, built on its base form
+    /// as the lemmatizer finds it, with the features its form shows, such
+    /// as the degree of `fairest` and the person of `thee`, or that
+    /// `features` lists for it, such as the type of `because` and the
+    /// sentiment of `alas`. The rest take their defaults. Early Modern
+    /// verb forms such as `hath` are recognised.
+    pub fn with_word(&self, word: &str) -> PartOfSpeech {
+        let surface_form = word.to_string();
+        let word = Word::new(word.to_string());
+        match self {
+            PartOfSpeechKind::Noun => {
+                let clitic = surface_form.strip_suffix("'s").unwrap_or(&surface_form);
+                let singular = inflection::singularize(clitic.trim_end_matches('\''));
+                PartOfSpeech::Noun(Word::with_surface_form(singular, surface_form))
+            }
+            PartOfSpeechKind::Pronoun => <PartOfSpeech as Pronoun>::from_form(&surface_form),
+            PartOfSpeechKind::Verb => PartOfSpeech::Verb(verb_word(surface_form)),
+            PartOfSpeechKind::Adjective => {
+                let (base_form, degree) = comparison::base_form(&word.surface_form);
+                let features = AdjectiveFeatures {
+                    degree,
+                    ..AdjectiveFeatures::default()
+                };
+                PartOfSpeech::Adjective(Word::with_surface_form(base_form, word.surface_form), features)
+            }
+            PartOfSpeechKind::Adverb => {
+                let features = features::adverb_features(&word.surface_form);
+                PartOfSpeech::Adverb(word, features)
+            }
+            PartOfSpeechKind::Preposition => {
+                let features = features::preposition_features(&word.surface_form);
+                PartOfSpeech::Preposition(word, features)
+            }
+            PartOfSpeechKind::Conjunction => {
+                let features = features::conjunction_features(&word.surface_form);
+                PartOfSpeech::Conjunction(word, features)
+            }
+            PartOfSpeechKind::Interjection => {
+                let features = features::interjection_features(&word.surface_form);
+                PartOfSpeech::Interjection(word, features)
+            }
+            PartOfSpeechKind::Article => PartOfSpeech::Article(word, ArticleFeatures::default()),
+            PartOfSpeechKind::Determiner => PartOfSpeech::Determiner(word),
+            PartOfSpeechKind::Numeral => PartOfSpeech::Numeral(word),
+            PartOfSpeechKind::Particle => PartOfSpeech::Particle(word),
+            PartOfSpeechKind::Auxiliary => PartOfSpeech::Auxiliary(verb_word(surface_form)),
+            PartOfSpeechKind::Modal => PartOfSpeech::Modal(verb_word(surface_form)),
+            PartOfSpeechKind::Punctuation => PartOfSpeech::Punctuation(word),
+        }
+    }
+}
+
+/// A verb, auxiliary or modal as it appeared, with the base form it was
+/// conjugated from.
+fn verb_word(surface_form: String) -> Word {
+    let base_form = conjugation::base_form(&surface_form, Dialect::EarlyModern);
+    Word::with_surface_form(base_form, surface_form)
+}
+
+impl PartOfSpeech {
+    pub fn kind(&self) -> PartOfSpeechKind {
+        match self {
+            PartOfSpeech::Noun(_) => PartOfSpeechKind::Noun,
+            PartOfSpeech::Pronoun(..) => PartOfSpeechKind::Pronoun,
+            PartOfSpeech::Verb(_) => PartOfSpeechKind::Verb,
+            PartOfSpeech::Adjective(..) => PartOfSpeechKind::Adjective,
+            PartOfSpeech::Adverb(..) => PartOfSpeechKind::Adverb,
+            PartOfSpeech::Preposition(..) => PartOfSpeechKind::Preposition,
+            PartOfSpeech::Conjunction(..) => PartOfSpeechKind::Conjunction,
+            PartOfSpeech::Interjection(..) => PartOfSpeechKind::Interjection,
+            PartOfSpeech::Article(..) => PartOfSpeechKind::Article,
+            PartOfSpeech::Determiner(_) => PartOfSpeechKind::Determiner,
+            PartOfSpeech::Numeral(_) => PartOfSpeechKind::Numeral,
+            PartOfSpeech::Particle(_) => PartOfSpeechKind::Particle,
+            PartOfSpeech::Auxiliary(_) => PartOfSpeechKind::Auxiliary,
+            PartOfSpeech::Modal(_) => PartOfSpeechKind::Modal,
+            PartOfSpeech::Punctuation(_) => PartOfSpeechKind::Punctuation,
+        }
+    }
+
+    /// The wrapped word. Not named `word`, which `Interjection` already
+    /// uses.
+    pub fn as_word(&self) -> &Word {
+        match self {
+            PartOfSpeech::Noun(word)
+            | PartOfSpeech::Pronoun(word, _, _)
+            | PartOfSpeech::Verb(word)
+            | PartOfSpeech::Adjective(word, _)
+            | PartOfSpeech::Adverb(word, _)
+            | PartOfSpeech::Preposition(word, _)
+            | PartOfSpeech::Conjunction(word, _)
+            | PartOfSpeech::Interjection(word, _)
+            | PartOfSpeech::Article(word, _)
+            | PartOfSpeech::Determiner(word)
+            | PartOfSpeech::Numeral(word)
+            | PartOfSpeech::Particle(word)
+            | PartOfSpeech::Auxiliary(word)
+            | PartOfSpeech::Modal(word)
+            | PartOfSpeech::Punctuation(word) => word,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        self.kind().label()
+    }
+
+    /// The Penn Treebank and Universal tags of this word.
+    pub fn tag(&self) -> Tag {
+        Tag::for_word(self.kind(), self.as_word().surface_form())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::word_enums::{Aspect, Tense};
+    use crate::tagger::LexiconTagger;
+    use crate::tokenizer::Tokenizer;
+
+    #[test]
+    fn displays_the_surface_form() {
+        assert_eq!(Word::new("dignity".to_string()).to_string(), "dignity");
+        let word = Word::with_surface_form("household".to_string(), "households".to_string());
+        assert_eq!(word.to_string(), "households");
+        assert_eq!(word.surface_form(), "households");
+        assert_eq!(<Word as Noun>::pluralize(&word), "households");
+        assert_eq!(PartOfSpeechKind::Noun.with_word("Verona").as_word().to_string(), "Verona");
+    }
+
+    #[test]
+    fn builds_noun_and_verb_forms_from_the_base_form() {
+        let noun = <PartOfSpeech as Noun>::new("scene");
+        assert_eq!(noun.pluralize(), "scenes");
+        assert_eq!(noun.singularize(), "scene");
+        assert_eq!(<PartOfSpeech as Noun>::new("child").pluralize(), "children");
+        assert_eq!(PartOfSpeechKind::Noun.with_word("ladies").singularize(), "lady");
+
+        let verb = <PartOfSpeech as Verb>::new("attend");
+        assert_eq!(verb.verb_base_form(), "attend");
+        assert_eq!(verb.past_tense(), "attended");
+        assert_eq!(verb.past_participle(), "attended");
+        assert_eq!(verb.present_participle(), "attending");
+        assert_eq!(verb.third_person_singular(), "attends");
+        assert_eq!(verb.present_tense_plural(), "attend");
+        assert_eq!(verb.infinitive(), "to attend");
+        let past = VerbFeatures {
+            tense: Tense::Past,
+            ..VerbFeatures::default()
+        };
+        assert_eq!(verb.conjugate(&past), "attended");
+        let verb = <PartOfSpeech as Verb>::new("run");
+        assert_eq!(verb.past_tense(), "ran");
+        assert_eq!(verb.present_participle(), "running");
+        assert_eq!(noun.past_tense(), "");
+    }
+
+    #[test]
+    fn builds_tagged_words_from_their_base_form() {
+        let households = PartOfSpeechKind::Noun.with_word("households");
+        assert_eq!(households.pluralize(), "households");
+        assert_eq!(households.singularize(), "household");
+        assert_eq!(households.as_word().to_string(), "households");
+        assert_eq!(PartOfSpeechKind::Noun.with_word("ladies").pluralize(), "ladies");
+        assert_eq!(PartOfSpeechKind::Noun.with_word("parents'").singularize(), "parent");
+
+        let moved = PartOfSpeechKind::Verb.with_word("moved");
+        assert_eq!(moved.verb_base_form(), "move");
+        assert_eq!(moved.past_tense(), "moved");
+        assert_eq!(moved.present_participle(), "moving");
+        assert_eq!(moved.as_word().to_string(), "moved");
+        assert_eq!(PartOfSpeechKind::Verb.with_word("hath").verb_base_form(), "have");
+
+        let was = PartOfSpeechKind::Auxiliary.with_word("was");
+        assert_eq!(was.verb_base_form(), "be");
+        assert_eq!(was.past_tense(), "was");
+        assert_eq!(was.third_person_singular(), "is");
+        let perfect = VerbFeatures {
+            aspect: Aspect::Perfect,
+            ..VerbFeatures::default()
+        };
+        assert_eq!(PartOfSpeechKind::Auxiliary.with_word("hath").conjugate(&perfect), "has had");
+        assert_eq!(PartOfSpeechKind::Modal.with_word("shalt").verb_base_form(), "shall");
+
+        let tokens = Tokenizer::default().tokenize("She loves the houses");
+        let tags: Vec<PartOfSpeech> = LexiconTagger::new()
+            .tag(&tokens)
+            .into_iter()
+            .flatten()
+            .collect();
+        let bases: Vec<String> = tags
+            .iter()
+            .filter_map(|tag| match tag {
+                PartOfSpeech::Noun(_) => Some(tag.singularize()),
+                PartOfSpeech::Verb(_) => Some(tag.verb_base_form()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(bases, ["love", "house"]);
+    }
+
+    #[test]
+    fn returns_the_base_form_of_every_class() {
+        let pronoun = <PartOfSpeech as Pronoun>::new(
+            "thou",
+            "thee",
+            "thy",
+            "thyself",
+            Person::Second,
+            Gender::Neutral,
+            Number::Singular,
+        );
+        assert_eq!(pronoun.subject_form(), "thou");
+        assert_eq!(pronoun.object_form(), "thee");
+        assert_eq!(pronoun.independent_possessive_form(), "thine");
+        assert_eq!(pronoun.pronoun_person(), Person::Second);
+        let them = <PartOfSpeech as Pronoun>::from_form("them");
+        assert_eq!(them.as_word().to_string(), "them");
+        assert_eq!(them.subject_form(), "they");
+        assert_eq!(them.possessive_form(), "their");
+        assert_eq!(them.pronoun_number(), Number::Plural);
+        let yourselves = <PartOfSpeech as Pronoun>::from_form("yourselves");
+        assert_eq!(yourselves.subject_form(), "you");
+        assert_eq!(yourselves.pronoun_number(), Number::Plural);
+        let who = <PartOfSpeech as Pronoun>::from_form("who");
+        assert_eq!(who.subject_form(), "who");
+        assert_eq!(who.pronoun_person(), Person::Third);
+        let adjective = <PartOfSpeech as Adjective>::new(
+            "civil",
+            Gender::Neutral,
+            Number::Singular,
+            Degree::Positive,
+            Position::Before,
+        );
+        assert_eq!(adjective.adjective_base_form(), "civil");
+        assert_eq!(
+            adjective.adjust_degree(Degree::Positive).adjective_base_form(),
+            "civil"
+        );
+        let compared = adjective.adjust_degree(Degree::Comparative);
+        assert_eq!(compared.as_word().surface_form(), "more civil");
+        assert_eq!(compared.adjective_degree(), Degree::Comparative);
+        assert_eq!(compared.adjective_base_form(), "civil");
+        let best = <PartOfSpeech as Adjective>::new(
+            "good",
+            Gender::Neutral,
+            Number::Singular,
+            Degree::Superlative,
+            Position::Before,
+        );
+        assert_eq!(best.as_word().surface_form(), "best");
+        assert_eq!(best.adjective_degree(), Degree::Superlative);
+        let adverb = <PartOfSpeech as Adverb>::new("quickly", Modifies::Verb, Position::After);
+        assert_eq!(adverb.adverb_base_form(), "quickly");
+        let preposition = <PartOfSpeech as Preposition>::new("of", PrepositionCase::Genitive);
+        assert_eq!(preposition.preposition_base_form(), "of");
+        let conjunction = <PartOfSpeech as Conjunction>::new(
+            "and",
+            ConjunctionType::Coordinating,
+            ConjunctionCategory::Addition,
+        );
+        assert_eq!(conjunction.base_form(), "and");
+        let interjection = <PartOfSpeech as Interjection>::new("ay", Sentiment::Approval);
+        assert_eq!(interjection.word(), "ay");
+        let article = <PartOfSpeech as Article>::new("the", Gender::Neutral, Number::Singular);
+        assert_eq!(article.form(), "the");
+        assert_eq!(article.kind(), PartOfSpeechKind::Article);
+        assert_eq!(article.as_word().to_string(), "the");
+    }
+
+    #[test]
+    fn keeps_the_forms_of_pronouns_outside_the_table() {
+        let xe = <PartOfSpeech as Pronoun>::new("xe", "xem", "xyr", "xemself", Person::Third, Gender::Neutral, Number::Singular);
+        assert_eq!(xe.subject_form(), "xe");
+        assert_eq!(xe.object_form(), "xem");
+        assert_eq!(xe.possessive_form(), "xyr");
+        assert_eq!(xe.independent_possessive_form(), "xyrs");
+        assert_eq!(xe.reflexive_form(), "xemself");
+        assert_eq!(xe.pronoun_person(), Person::Third);
+        assert_eq!(xe.as_word().to_string(), "xe");
+    }
+
+    #[test]
+    fn remembers_the_features_it_was_built_with() {
+        let adjective = <PartOfSpeech as Adjective>::new(
+            "fair",
+            Gender::Feminine,
+            Number::Plural,
+            Degree::Superlative,
+            Position::After,
+        );
+        assert_eq!(adjective.as_word().surface_form(), "fairest");
+        assert_eq!(adjective.adjective_gender(), Gender::Feminine);
+        assert_eq!(adjective.adjective_number(), Number::Plural);
+        assert_eq!(adjective.adjective_degree(), Degree::Superlative);
+        assert_eq!(adjective.adjective_position(), Position::After);
+        let fairer = adjective.adjust_degree(Degree::Comparative);
+        assert_eq!(fairer.adjective_degree(), Degree::Comparative);
+        assert_eq!(fairer.adjective_position(), Position::After);
+        let adverb = <PartOfSpeech as Adverb>::new("very", Modifies::Adjective, Position::Before);
+        assert_eq!(adverb.modifies(), Modifies::Adjective);
+        assert_eq!(adverb.adverb_position(), Position::Before);
+        let preposition = <PartOfSpeech as Preposition>::new("of", PrepositionCase::Genitive);
+        assert_eq!(preposition.case(), PrepositionCase::Genitive);
+        let conjunction = <PartOfSpeech as Conjunction>::new(
+            "because",
+            ConjunctionType::Subordinating,
+            ConjunctionCategory::Causal,
+        );
+        assert_eq!(conjunction.conjunction_type(), ConjunctionType::Subordinating);
+        assert_eq!(conjunction.category(), ConjunctionCategory::Causal);
+        let interjection = <PartOfSpeech as Interjection>::new("alas", Sentiment::Pain);
+        assert_eq!(interjection.sentiment(), Sentiment::Pain);
+        let article = <PartOfSpeech as Article>::new("the", Gender::Neutral, Number::Plural);
+        assert_eq!(article.article_number(), Number::Plural);
+        let pronoun = <PartOfSpeech as Pronoun>::new(
+            "they",
+            "them",
+            "their",
+            "themselves",
+            Person::First,
+            Gender::Feminine,
+            Number::Plural,
+        );
+        assert_eq!(pronoun.pronoun_gender(), Gender::Feminine);
+        assert_eq!(pronoun.pronoun_number(), Number::Plural);
+        assert_eq!(pronoun.pronoun_person(), Person::First);
+    }
+
+    #[test]
+    fn takes_the_features_a_tagged_form_shows() {
+        let fairest = PartOfSpeechKind::Adjective.with_word("fairest");
+        assert_eq!(fairest.adjective_degree(), Degree::Superlative);
+        assert_eq!(fairest.adjective_position(), Position::Before);
+        assert_eq!(fairest.adjective_base_form(), "fair");
+        assert_eq!(fairest.as_word().to_string(), "fairest");
+        let thee = PartOfSpeechKind::Pronoun.with_word("thee");
+        assert_eq!(thee.pronoun_person(), Person::Second);
+        assert_eq!(thee.subject_form(), "thou");
+        assert_eq!(thee, <PartOfSpeech as Pronoun>::from_form("thee"));
+        let because = PartOfSpeechKind::Conjunction.with_word("Because");
+        assert_eq!(because.conjunction_type(), ConjunctionType::Subordinating);
+        assert_eq!(because.category(), ConjunctionCategory::Causal);
+        assert_eq!(PartOfSpeechKind::Interjection.with_word("alas").sentiment(), Sentiment::Pain);
+        assert_eq!(PartOfSpeechKind::Interjection.with_word("O").sentiment(), Sentiment::Neutral);
+        assert_eq!(PartOfSpeechKind::Preposition.with_word("of").case(), PrepositionCase::Genitive);
+        assert_eq!(PartOfSpeechKind::Adverb.with_word("very").modifies(), Modifies::Adjective);
+    }
+}
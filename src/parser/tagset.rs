@@ -0,0 +1,772 @@
+use std::fmt::{self, Display};
+
+use super::comparison;
+use super::conjugation;
+use super::declension;
+use super::inflection;
+use super::part_of_speech::PartOfSpeechKind;
+use super::word_enums::{Degree, Dialect};
+
+/// The articles, which Penn and Universal Dependencies tag as determiners.
+const ARTICLES: [&str; 4] = ["a", "an", "the", "th'"];
+
+/// Wh-words Penn tags `WP` as pronouns.
+const WH_PRONOUNS: [&str; 6] = ["who", "whom", "what", "whoever", "whomever", "whatever"];
+
+/// Wh-words Penn tags `WDT`, as pronouns or determiners.
+const WH_DETERMINERS: [&str; 4] = ["which", "whichever", "what", "whatever"];
+
+/// Wh-words Penn tags `WRB`.
+const WH_ADVERBS: [&str; 9] = [
+    "where",
+    "when",
+    "why",
+    "how",
+    "whence",
+    "whither",
+    "wherefore",
+    "whereby",
+    "wherein",
+];
+
+/// The tags of the Penn Treebank, with the punctuation and OntoNotes
+/// additions used by most English corpora.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PennTag {
+    CoordinatingConjunction,
+    CardinalNumber,
+    Determiner,
+    ExistentialThere,
+    ForeignWord,
+    Preposition,
+    Adjective,
+    ComparativeAdjective,
+    SuperlativeAdjective,
+    ListMarker,
+    Modal,
+    Noun,
+    PluralNoun,
+    ProperNoun,
+    PluralProperNoun,
+    Predeterminer,
+    PossessiveEnding,
+    PersonalPronoun,
+    PossessivePronoun,
+    Adverb,
+    ComparativeAdverb,
+    SuperlativeAdverb,
+    Particle,
+    Symbol,
+    To,
+    Interjection,
+    BaseVerb,
+    PastTenseVerb,
+    Gerund,
+    PastParticiple,
+    PresentVerb,
+    ThirdPersonVerb,
+    WhDeterminer,
+    WhPronoun,
+    PossessiveWhPronoun,
+    WhAdverb,
+    Dollar,
+    Pound,
+    OpeningQuote,
+    ClosingQuote,
+    OpeningBracket,
+    ClosingBracket,
+    Comma,
+    SentenceFinal,
+    MidSentence,
+    Hyphen,
+    NonFinalPunctuation,
+    Address,
+    Affix,
+    Missing,
+}
+
+impl PennTag {
+    pub const ALL: [PennTag; 50] = [
+        PennTag::CoordinatingConjunction,
+        PennTag::CardinalNumber,
+        PennTag::Determiner,
+        PennTag::ExistentialThere,
+        PennTag::ForeignWord,
+        PennTag::Preposition,
+        PennTag::Adjective,
+        PennTag::ComparativeAdjective,
+        PennTag::SuperlativeAdjective,
+        PennTag::ListMarker,
+        PennTag::Modal,
+        PennTag::Noun,
+        PennTag::PluralNoun,
+        PennTag::ProperNoun,
+        PennTag::PluralProperNoun,
+        PennTag::Predeterminer,
+        PennTag::PossessiveEnding,
+        PennTag::PersonalPronoun,
+        PennTag::PossessivePronoun,
+        PennTag::Adverb,
+        PennTag::ComparativeAdverb,
+        PennTag::SuperlativeAdverb,
+        PennTag::Particle,
+        PennTag::Symbol,
+        PennTag::To,
+        PennTag::Interjection,
+        PennTag::BaseVerb,
+        PennTag::PastTenseVerb,
+        PennTag::Gerund,
+        PennTag::PastParticiple,
+        PennTag::PresentVerb,
+        PennTag::ThirdPersonVerb,
+        PennTag::WhDeterminer,
+        PennTag::WhPronoun,
+        PennTag::PossessiveWhPronoun,
+        PennTag::WhAdverb,
+        PennTag::Dollar,
+        PennTag::Pound,
+        PennTag::OpeningQuote,
+        PennTag::ClosingQuote,
+        PennTag::OpeningBracket,
+        PennTag::ClosingBracket,
+        PennTag::Comma,
+        PennTag::SentenceFinal,
+        PennTag::MidSentence,
+        PennTag::Hyphen,
+        PennTag::NonFinalPunctuation,
+        PennTag::Address,
+        PennTag::Affix,
+        PennTag::Missing,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            PennTag::CoordinatingConjunction => "CC",
+            PennTag::CardinalNumber => "CD",
+            PennTag::Determiner => "DT",
+            PennTag::ExistentialThere => "EX",
+            PennTag::ForeignWord => "FW",
+            PennTag::Preposition => "IN",
+            PennTag::Adjective => "JJ",
+            PennTag::ComparativeAdjective => "JJR",
+            PennTag::SuperlativeAdjective => "JJS",
+            PennTag::ListMarker => "LS",
+            PennTag::Modal => "MD",
+            PennTag::Noun => "NN",
+            PennTag::PluralNoun => "NNS",
+            PennTag::ProperNoun => "NNP",
+            PennTag::PluralProperNoun => "NNPS",
+            PennTag::Predeterminer => "PDT",
+            PennTag::PossessiveEnding => "POS",
+            PennTag::PersonalPronoun => "PRP",
+            PennTag::PossessivePronoun => "PRP$",
+            PennTag::Adverb => "RB",
+            PennTag::ComparativeAdverb => "RBR",
+            PennTag::SuperlativeAdverb => "RBS",
+            PennTag::Particle => "RP",
+            PennTag::Symbol => "SYM",
+            PennTag::To => "TO",
+            PennTag::Interjection => "UH",
+            PennTag::BaseVerb => "VB",
+            PennTag::PastTenseVerb => "VBD",
+            PennTag::Gerund => "VBG",
+            PennTag::PastParticiple => "VBN",
+            PennTag::PresentVerb => "VBP",
+            PennTag::ThirdPersonVerb => "VBZ",
+            PennTag::WhDeterminer => "WDT",
+            PennTag::WhPronoun => "WP",
+            PennTag::PossessiveWhPronoun => "WP$",
+            PennTag::WhAdverb => "WRB",
+            PennTag::Dollar => "$",
+            PennTag::Pound => "#",
+            PennTag::OpeningQuote => "``",
+            PennTag::ClosingQuote => "''",
+            PennTag::OpeningBracket => "-LRB-",
+            PennTag::ClosingBracket => "-RRB-",
+            PennTag::Comma => ",",
+            PennTag::SentenceFinal => ".",
+            PennTag::MidSentence => ":",
+            PennTag::Hyphen => "HYPH",
+            PennTag::NonFinalPunctuation => "NFP",
+            PennTag::Address => "ADD",
+            PennTag::Affix => "AFX",
+            PennTag::Missing => "XX",
+        }
+    }
+
+    /// Also accepts the bracket spellings `(`, `)`, `-LCB-` and the like
+    /// found in some releases.
+    pub fn from_label(label: &str) -> Option<PennTag> {
+        match label {
+            "(" | "-LCB-" | "-LSB-" => Some(PennTag::OpeningBracket),
+            ")" | "-RCB-" | "-RSB-" => Some(PennTag::ClosingBracket),
+            label => PennTag::ALL.into_iter().find(|tag| tag.label() == label),
+        }
+    }
+
+    /// The tag of a punctuation or symbol token.
+    pub fn for_punctuation(text: &str) -> PennTag {
+        match text {
+            "," => PennTag::Comma,
+            "." | "!" | "?" => PennTag::SentenceFinal,
+            ":" | ";" | "--" | "\u{2014}" | "\u{2013}" | "..." | "\u{2026}" => PennTag::MidSentence,
+            "(" | "[" | "{" => PennTag::OpeningBracket,
+            ")" | "]" | "}" => PennTag::ClosingBracket,
+            "``" | "\u{201c}" | "\u{2018}" => PennTag::OpeningQuote,
+            "''" | "\"" | "'" | "\u{201d}" | "\u{2019}" => PennTag::ClosingQuote,
+            "$" | "\u{a3}" | "\u{20ac}" => PennTag::Dollar,
+            "#" => PennTag::Pound,
+            "-" => PennTag::Hyphen,
+            _ => PennTag::NonFinalPunctuation,
+        }
+    }
+
+    /// The Universal tag the standard conversion gives this tag.
+    pub fn universal(&self) -> UniversalTag {
+        match self {
+            PennTag::CoordinatingConjunction => UniversalTag::CoordinatingConjunction,
+            PennTag::CardinalNumber => UniversalTag::Numeral,
+            PennTag::Determiner | PennTag::Predeterminer => UniversalTag::Determiner,
+            PennTag::ExistentialThere
+            | PennTag::PersonalPronoun
+            | PennTag::PossessivePronoun
+            | PennTag::WhDeterminer
+            | PennTag::WhPronoun
+            | PennTag::PossessiveWhPronoun => UniversalTag::Pronoun,
+            PennTag::Preposition | PennTag::Particle => UniversalTag::Adposition,
+            PennTag::Adjective
+            | PennTag::ComparativeAdjective
+            | PennTag::SuperlativeAdjective
+            | PennTag::Affix => UniversalTag::Adjective,
+            PennTag::Modal => UniversalTag::Auxiliary,
+            PennTag::Noun | PennTag::PluralNoun => UniversalTag::Noun,
+            PennTag::ProperNoun | PennTag::PluralProperNoun => UniversalTag::ProperNoun,
+            PennTag::PossessiveEnding | PennTag::To => UniversalTag::Particle,
+            PennTag::Adverb
+            | PennTag::ComparativeAdverb
+            | PennTag::SuperlativeAdverb
+            | PennTag::WhAdverb => UniversalTag::Adverb,
+            PennTag::Symbol | PennTag::Dollar | PennTag::Pound => UniversalTag::Symbol,
+            PennTag::Interjection => UniversalTag::Interjection,
+            PennTag::BaseVerb
+            | PennTag::PastTenseVerb
+            | PennTag::Gerund
+            | PennTag::PastParticiple
+            | PennTag::PresentVerb
+            | PennTag::ThirdPersonVerb => UniversalTag::Verb,
+            PennTag::OpeningQuote
+            | PennTag::ClosingQuote
+            | PennTag::OpeningBracket
+            | PennTag::ClosingBracket
+            | PennTag::Comma
+            | PennTag::SentenceFinal
+            | PennTag::MidSentence
+            | PennTag::Hyphen
+            | PennTag::NonFinalPunctuation => UniversalTag::Punctuation,
+            PennTag::ForeignWord | PennTag::ListMarker | PennTag::Address | PennTag::Missing => {
+                UniversalTag::Other
+            }
+        }
+    }
+}
+
+impl Display for PennTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// The seventeen Universal Dependencies part-of-speech tags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UniversalTag {
+    Adjective,
+    Adposition,
+    Adverb,
+    Auxiliary,
+    CoordinatingConjunction,
+    Determiner,
+    Interjection,
+    Noun,
+    Numeral,
+    Particle,
+    Pronoun,
+    ProperNoun,
+    Punctuation,
+    SubordinatingConjunction,
+    Symbol,
+    Verb,
+    Other,
+}
+
+impl UniversalTag {
+    pub const ALL: [UniversalTag; 17] = [
+        UniversalTag::Adjective,
+        UniversalTag::Adposition,
+        UniversalTag::Adverb,
+        UniversalTag::Auxiliary,
+        UniversalTag::CoordinatingConjunction,
+        UniversalTag::Determiner,
+        UniversalTag::Interjection,
+        UniversalTag::Noun,
+        UniversalTag::Numeral,
+        UniversalTag::Particle,
+        UniversalTag::Pronoun,
+        UniversalTag::ProperNoun,
+        UniversalTag::Punctuation,
+        UniversalTag::SubordinatingConjunction,
+        UniversalTag::Symbol,
+        UniversalTag::Verb,
+        UniversalTag::Other,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            UniversalTag::Adjective => "ADJ",
+            UniversalTag::Adposition => "ADP",
+            UniversalTag::Adverb => "ADV",
+            UniversalTag::Auxiliary => "AUX",
+            UniversalTag::CoordinatingConjunction => "CCONJ",
+            UniversalTag::Determiner => "DET",
+            UniversalTag::Interjection => "INTJ",
+            UniversalTag::Noun => "NOUN",
+            UniversalTag::Numeral => "NUM",
+            UniversalTag::Particle => "PART",
+            UniversalTag::Pronoun => "PRON",
+            UniversalTag::ProperNoun => "PROPN",
+            UniversalTag::Punctuation => "PUNCT",
+            UniversalTag::SubordinatingConjunction => "SCONJ",
+            UniversalTag::Symbol => "SYM",
+            UniversalTag::Verb => "VERB",
+            UniversalTag::Other => "X",
+        }
+    }
+
+    /// Also accepts `CONJ`, the version 1 name of `CCONJ`.
+    pub fn from_label(label: &str) -> Option<UniversalTag> {
+        match label {
+            "CONJ" => Some(UniversalTag::CoordinatingConjunction),
+            label => UniversalTag::ALL
+                .into_iter()
+                .find(|tag| tag.label() == label),
+        }
+    }
+
+    /// The Penn tag most words with this tag have.
+    pub fn penn(&self) -> PennTag {
+        match self {
+            UniversalTag::Adjective => PennTag::Adjective,
+            UniversalTag::Adposition | UniversalTag::SubordinatingConjunction => {
+                PennTag::Preposition
+            }
+            UniversalTag::Adverb => PennTag::Adverb,
+            UniversalTag::Auxiliary | UniversalTag::Verb => PennTag::BaseVerb,
+            UniversalTag::CoordinatingConjunction => PennTag::CoordinatingConjunction,
+            UniversalTag::Determiner => PennTag::Determiner,
+            UniversalTag::Interjection => PennTag::Interjection,
+            UniversalTag::Noun => PennTag::Noun,
+            UniversalTag::Numeral => PennTag::CardinalNumber,
+            UniversalTag::Particle => PennTag::Particle,
+            UniversalTag::Pronoun => PennTag::PersonalPronoun,
+            UniversalTag::ProperNoun => PennTag::ProperNoun,
+            UniversalTag::Punctuation => PennTag::NonFinalPunctuation,
+            UniversalTag::Symbol => PennTag::Symbol,
+            UniversalTag::Other => PennTag::ForeignWord,
+        }
+    }
+
+    /// Our class for this tag, or `None` for `X`.
+    pub fn kind(&self) -> Option<PartOfSpeechKind> {
+        Some(match self {
+            UniversalTag::Adjective => PartOfSpeechKind::Adjective,
+            UniversalTag::Adposition => PartOfSpeechKind::Preposition,
+            UniversalTag::Adverb => PartOfSpeechKind::Adverb,
+            UniversalTag::Auxiliary => PartOfSpeechKind::Auxiliary,
+            UniversalTag::CoordinatingConjunction | UniversalTag::SubordinatingConjunction => {
+                PartOfSpeechKind::Conjunction
+            }
+            UniversalTag::Determiner => PartOfSpeechKind::Determiner,
+            UniversalTag::Interjection => PartOfSpeechKind::Interjection,
+            UniversalTag::Noun | UniversalTag::ProperNoun => PartOfSpeechKind::Noun,
+            UniversalTag::Numeral => PartOfSpeechKind::Numeral,
+            UniversalTag::Particle => PartOfSpeechKind::Particle,
+            UniversalTag::Pronoun => PartOfSpeechKind::Pronoun,
+            UniversalTag::Punctuation | UniversalTag::Symbol => PartOfSpeechKind::Punctuation,
+            UniversalTag::Verb => PartOfSpeechKind::Verb,
+            UniversalTag::Other => return None,
+        })
+    }
+}
+
+impl Display for UniversalTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// A tag seen in all three tagsets at once.
+///
+/// Converting into a `Tag` keeps whichever tag it came from and fills in
+/// the other two, so converting back out again gives the original: a Penn
+/// `VBZ` read into a `Tag` is written back as `VBZ`, not as the `VB` of its
+/// coarse class. `Tag::new` pairs a Penn and a Universal tag read from the
+/// same token, as in the two tag columns of a CoNLL-U file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Tag {
+    kind: Option<PartOfSpeechKind>,
+    penn: PennTag,
+    universal: UniversalTag,
+}
+
+impl Tag {
+    pub fn new(penn: PennTag, universal: UniversalTag) -> Tag {
+        let kind = match (penn, universal) {
+            (PennTag::Modal, _) => Some(PartOfSpeechKind::Modal),
+            (PennTag::Particle, _) => Some(PartOfSpeechKind::Particle),
+            (PennTag::Determiner, _) | (_, UniversalTag::Determiner) => {
+                Some(PartOfSpeechKind::Determiner)
+            }
+            (PennTag::CardinalNumber, _) => Some(PartOfSpeechKind::Numeral),
+            _ => universal.kind().or_else(|| penn.universal().kind()),
+        };
+        Tag {
+            kind,
+            penn,
+            universal,
+        }
+    }
+
+    /// The tag of a word of class `kind`, refined from its spelling where
+    /// Penn makes finer distinctions: plural nouns, the forms of verbs,
+    /// superlatives, `to` and `not`, possessive and wh- pronouns, wh-
+    /// determiners and adverbs, and the kind of punctuation.
+    pub fn for_word(kind: PartOfSpeechKind, word: &str) -> Tag {
+        let lowercase = word.to_lowercase().replace('\u{2019}', "'");
+        let penn = match kind {
+            PartOfSpeechKind::Noun if is_plural_noun(&lowercase) => Some(PennTag::PluralNoun),
+            PartOfSpeechKind::Verb | PartOfSpeechKind::Auxiliary => Some(verb_tag(&lowercase)),
+            PartOfSpeechKind::Adjective => match comparison::base_form(&lowercase).1 {
+                Degree::Positive => None,
+                Degree::Comparative => Some(PennTag::ComparativeAdjective),
+                Degree::Superlative => Some(PennTag::SuperlativeAdjective),
+            },
+            PartOfSpeechKind::Particle if lowercase == "to" => Some(PennTag::To),
+            PartOfSpeechKind::Particle if lowercase == "not" || lowercase == "n't" => {
+                Some(PennTag::Adverb)
+            }
+            PartOfSpeechKind::Preposition if lowercase == "to" => Some(PennTag::To),
+            PartOfSpeechKind::Pronoun if lowercase == "whose" => Some(PennTag::PossessiveWhPronoun),
+            PartOfSpeechKind::Pronoun if WH_PRONOUNS.contains(&lowercase.as_str()) => {
+                Some(PennTag::WhPronoun)
+            }
+            PartOfSpeechKind::Pronoun | PartOfSpeechKind::Determiner
+                if WH_DETERMINERS.contains(&lowercase.as_str()) =>
+            {
+                Some(PennTag::WhDeterminer)
+            }
+            PartOfSpeechKind::Pronoun | PartOfSpeechKind::Determiner
+                if is_possessive_pronoun(&lowercase) =>
+            {
+                Some(PennTag::PossessivePronoun)
+            }
+            PartOfSpeechKind::Adverb if WH_ADVERBS.contains(&lowercase.as_str()) => {
+                Some(PennTag::WhAdverb)
+            }
+            PartOfSpeechKind::Punctuation => Some(PennTag::for_punctuation(word)),
+            _ => None,
+        };
+        let tag = Tag::from(kind);
+        Tag {
+            penn: penn.unwrap_or(tag.penn),
+            ..tag
+        }
+    }
+
+    /// This tag with its class narrowed down by `word` where the tag alone
+    /// cannot tell: articles among determiners, modals and the forms of
+    /// `be` among verbs, and `not` among adverbs. Penn still leaves
+    /// auxiliary `have` and `do` with the verbs, `to` with the particles
+    /// and wh- determiners with the pronouns.
+    pub fn refine(self, word: &str) -> Tag {
+        let lowercase = word.to_lowercase().replace('\u{2019}', "'");
+        let kind = match self.kind {
+            Some(PartOfSpeechKind::Determiner) if ARTICLES.contains(&lowercase.as_str()) => {
+                PartOfSpeechKind::Article
+            }
+            Some(PartOfSpeechKind::Verb | PartOfSpeechKind::Auxiliary)
+                if conjugation::is_modal(&lowercase) =>
+            {
+                PartOfSpeechKind::Modal
+            }
+            Some(PartOfSpeechKind::Verb)
+                if conjugation::base_form(&lowercase, Dialect::EarlyModern) == "be" =>
+            {
+                PartOfSpeechKind::Auxiliary
+            }
+            Some(PartOfSpeechKind::Adverb) if lowercase == "not" || lowercase == "n't" => {
+                PartOfSpeechKind::Particle
+            }
+            _ => return self,
+        };
+        Tag {
+            kind: Some(kind),
+            ..self
+        }
+    }
+
+    /// Our class, or `None` for foreign words and other tags outside it.
+    pub fn kind(&self) -> Option<PartOfSpeechKind> {
+        self.kind
+    }
+
+    pub fn penn(&self) -> PennTag {
+        self.penn
+    }
+
+    pub fn universal(&self) -> UniversalTag {
+        self.universal
+    }
+}
+
+/// Whether `word` is a plural noun, possessive or not.
+fn is_plural_noun(word: &str) -> bool {
+    let noun = word
+        .strip_suffix("'s")
+        .or_else(|| word.strip_suffix('\''))
+        .unwrap_or(word);
+    inflection::singularize(noun) != noun
+}
+
+/// The Penn tag of a form of a verb or auxiliary, found by matching it
+/// against the forms its base form makes. Words that are verbs in their
+/// own right, like `lay`, are taken as base forms, and regular pasts as
+/// `VBD`, since without context they could as well be `VBN`.
+fn verb_tag(word: &str) -> PennTag {
+    let base = conjugation::base_form(word, Dialect::EarlyModern);
+    let is = |form: String| form == word;
+    if word == base || conjugation::is_irregular(word) {
+        PennTag::BaseVerb
+    } else if word.ends_with("ing") {
+        PennTag::Gerund
+    } else if is(conjugation::third_person_singular(&base))
+        || is(conjugation::archaic_third_person(&base))
+    {
+        PennTag::ThirdPersonVerb
+    } else if is(conjugation::past_tense(&base))
+        || is(conjugation::archaic_second_person_past(&base))
+        || matches!(word, "were" | "wert")
+    {
+        PennTag::PastTenseVerb
+    } else if is(conjugation::past_participle(&base)) {
+        PennTag::PastParticiple
+    } else if is(conjugation::present_plural(&base))
+        || is(conjugation::archaic_second_person(&base))
+        || matches!(word, "am" | "beest")
+        || word.ends_with("'st")
+    {
+        PennTag::PresentVerb
+    } else if word.ends_with("ed") || word.ends_with("'d") {
+        PennTag::PastTenseVerb
+    } else {
+        PennTag::BaseVerb
+    }
+}
+
+/// Whether `word` is the possessive form of a personal pronoun used before
+/// a noun, as `their` is. `her`, also an object, is left to `PRP`.
+fn is_possessive_pronoun(word: &str) -> bool {
+    declension::paradigm(word)
+        .is_some_and(|paradigm| paradigm.possessive == word && paradigm.object != word)
+}
+
+impl From<PennTag> for Tag {
+    fn from(penn: PennTag) -> Tag {
+        Tag::new(penn, penn.universal())
+    }
+}
+
+impl From<UniversalTag> for Tag {
+    fn from(universal: UniversalTag) -> Tag {
+        Tag {
+            kind: universal.kind(),
+            penn: universal.penn(),
+            universal,
+        }
+    }
+}
+
+impl From<PartOfSpeechKind> for Tag {
+    fn from(kind: PartOfSpeechKind) -> Tag {
+        let (penn, universal) = match kind {
+            PartOfSpeechKind::Noun => (PennTag::Noun, UniversalTag::Noun),
+            PartOfSpeechKind::Pronoun => (PennTag::PersonalPronoun, UniversalTag::Pronoun),
+            PartOfSpeechKind::Verb => (PennTag::BaseVerb, UniversalTag::Verb),
+            PartOfSpeechKind::Adjective => (PennTag::Adjective, UniversalTag::Adjective),
+            PartOfSpeechKind::Adverb => (PennTag::Adverb, UniversalTag::Adverb),
+            PartOfSpeechKind::Preposition => (PennTag::Preposition, UniversalTag::Adposition),
+            PartOfSpeechKind::Conjunction => (
+                PennTag::CoordinatingConjunction,
+                UniversalTag::CoordinatingConjunction,
+            ),
+            PartOfSpeechKind::Interjection => (PennTag::Interjection, UniversalTag::Interjection),
+            PartOfSpeechKind::Article | PartOfSpeechKind::Determiner => {
+                (PennTag::Determiner, UniversalTag::Determiner)
+            }
+            PartOfSpeechKind::Numeral => (PennTag::CardinalNumber, UniversalTag::Numeral),
+            PartOfSpeechKind::Particle => (PennTag::Particle, UniversalTag::Particle),
+            PartOfSpeechKind::Auxiliary => (PennTag::BaseVerb, UniversalTag::Auxiliary),
+            PartOfSpeechKind::Modal => (PennTag::Modal, UniversalTag::Auxiliary),
+            PartOfSpeechKind::Punctuation => {
+                (PennTag::NonFinalPunctuation, UniversalTag::Punctuation)
+            }
+        };
+        Tag {
+            kind: Some(kind),
+            penn,
+            universal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tagger::parse_tagged;
+
+    const TAGGED: &str = include_str!("../tagger/romeo_tagged.txt");
+
+    #[test]
+    fn labels_round_trip() {
+        for tag in PennTag::ALL {
+            assert_eq!(PennTag::from_label(tag.label()), Some(tag));
+            assert_eq!(Tag::from(tag).penn(), tag);
+        }
+        for tag in UniversalTag::ALL {
+            assert_eq!(UniversalTag::from_label(tag.label()), Some(tag));
+            assert_eq!(Tag::from(tag).universal(), tag);
+        }
+        for kind in PartOfSpeechKind::ALL {
+            assert_eq!(Tag::from(kind).kind(), Some(kind));
+        }
+    }
+
+    #[test]
+    fn converts_between_tagsets() {
+        let tag = Tag::from(PennTag::ThirdPersonVerb);
+        assert_eq!(tag.universal(), UniversalTag::Verb);
+        assert_eq!(tag.kind(), Some(PartOfSpeechKind::Verb));
+
+        let tag = Tag::from(PennTag::Modal);
+        assert_eq!(tag.universal(), UniversalTag::Auxiliary);
+        assert_eq!(tag.kind(), Some(PartOfSpeechKind::Modal));
+
+        let tag = Tag::new(PennTag::ThirdPersonVerb, UniversalTag::Auxiliary);
+        assert_eq!(tag.kind(), Some(PartOfSpeechKind::Auxiliary));
+        assert_eq!(Tag::from(UniversalTag::Other).kind(), None);
+        assert_eq!(PennTag::from_label("-LCB-"), Some(PennTag::OpeningBracket));
+        assert_eq!(
+            UniversalTag::from_label("CONJ"),
+            Some(UniversalTag::CoordinatingConjunction)
+        );
+    }
+
+    #[test]
+    fn refines_from_spelling() {
+        let penn = |kind, word| Tag::for_word(kind, word).penn().label();
+        assert_eq!(penn(PartOfSpeechKind::Noun, "households"), "NNS");
+        assert_eq!(penn(PartOfSpeechKind::Noun, "mistress"), "NN");
+        assert_eq!(penn(PartOfSpeechKind::Noun, "parents'"), "NNS");
+        assert_eq!(penn(PartOfSpeechKind::Noun, "Montague's"), "NN");
+        for word in ["news", "bus", "species", "Thomas", "Chorus"] {
+            assert_eq!(penn(PartOfSpeechKind::Noun, word), "NN", "{}", word);
+        }
+        assert_eq!(penn(PartOfSpeechKind::Verb, "being"), "VBG");
+        assert_eq!(penn(PartOfSpeechKind::Auxiliary, "Is"), "VBZ");
+        assert_eq!(penn(PartOfSpeechKind::Auxiliary, "Doth"), "VBZ");
+        assert_eq!(penn(PartOfSpeechKind::Auxiliary, "art"), "VBP");
+        assert_eq!(penn(PartOfSpeechKind::Verb, "runn'st"), "VBP");
+        assert_eq!(penn(PartOfSpeechKind::Auxiliary, "were"), "VBD");
+        assert_eq!(penn(PartOfSpeechKind::Auxiliary, "been"), "VBN");
+        assert_eq!(penn(PartOfSpeechKind::Verb, "makes"), "VBZ");
+        assert_eq!(penn(PartOfSpeechKind::Verb, "needs"), "VBZ");
+        assert_eq!(penn(PartOfSpeechKind::Verb, "taken"), "VBN");
+        assert_eq!(penn(PartOfSpeechKind::Verb, "took"), "VBD");
+        assert_eq!(penn(PartOfSpeechKind::Verb, "moved"), "VBD");
+        assert_eq!(penn(PartOfSpeechKind::Verb, "need"), "VB");
+        assert_eq!(penn(PartOfSpeechKind::Verb, "lay"), "VB");
+        assert_eq!(penn(PartOfSpeechKind::Verb, "bleed"), "VB");
+        assert_eq!(penn(PartOfSpeechKind::Particle, "to"), "TO");
+        assert_eq!(penn(PartOfSpeechKind::Preposition, "to"), "TO");
+        assert_eq!(penn(PartOfSpeechKind::Particle, "not"), "RB");
+        assert_eq!(penn(PartOfSpeechKind::Adjective, "weakest"), "JJS");
+        assert_eq!(penn(PartOfSpeechKind::Punctuation, ","), ",");
+        assert_eq!(penn(PartOfSpeechKind::Punctuation, "?"), ".");
+        assert_eq!(penn(PartOfSpeechKind::Modal, "shall"), "MD");
+        assert_eq!(penn(PartOfSpeechKind::Pronoun, "their"), "PRP$");
+        assert_eq!(penn(PartOfSpeechKind::Pronoun, "Thy"), "PRP$");
+        assert_eq!(penn(PartOfSpeechKind::Pronoun, "his"), "PRP$");
+        assert_eq!(penn(PartOfSpeechKind::Pronoun, "her"), "PRP");
+        assert_eq!(penn(PartOfSpeechKind::Pronoun, "thine"), "PRP");
+        assert_eq!(penn(PartOfSpeechKind::Pronoun, "Who"), "WP");
+        assert_eq!(penn(PartOfSpeechKind::Pronoun, "whose"), "WP$");
+        assert_eq!(penn(PartOfSpeechKind::Pronoun, "which"), "WDT");
+        assert_eq!(penn(PartOfSpeechKind::Determiner, "what"), "WDT");
+        assert_eq!(penn(PartOfSpeechKind::Pronoun, "what"), "WP");
+        assert_eq!(penn(PartOfSpeechKind::Adverb, "Wherefore"), "WRB");
+        assert_eq!(penn(PartOfSpeechKind::Adverb, "here"), "RB");
+        let their = Tag::for_word(PartOfSpeechKind::Pronoun, "their");
+        assert_eq!(their.universal(), UniversalTag::Pronoun);
+    }
+
+    #[test]
+    fn refines_classes_from_the_word() {
+        let kind = |tag: PennTag, word| Tag::from(tag).refine(word).kind().unwrap();
+        assert_eq!(kind(PennTag::Determiner, "The"), PartOfSpeechKind::Article);
+        assert_eq!(
+            kind(PennTag::Determiner, "this"),
+            PartOfSpeechKind::Determiner
+        );
+        assert_eq!(
+            kind(PennTag::ThirdPersonVerb, "is"),
+            PartOfSpeechKind::Auxiliary
+        );
+        assert_eq!(
+            kind(PennTag::ThirdPersonVerb, "hath"),
+            PartOfSpeechKind::Verb
+        );
+        assert_eq!(kind(PennTag::PresentVerb, "shalt"), PartOfSpeechKind::Modal);
+        assert_eq!(kind(PennTag::Adverb, "not"), PartOfSpeechKind::Particle);
+        assert_eq!(kind(PennTag::Particle, "up"), PartOfSpeechKind::Particle);
+        let modal = Tag::from(UniversalTag::Auxiliary).refine("would");
+        assert_eq!(modal.kind(), Some(PartOfSpeechKind::Modal));
+    }
+
+    #[test]
+    fn hand_tagged_words_survive_the_standard_tagsets() {
+        // Penn gives auxiliary `have` and `do` the tags of main verbs, and
+        // the preposition `to` the tag of the infinitive marker.
+        let shared = [
+            (PartOfSpeechKind::Verb, PartOfSpeechKind::Auxiliary),
+            (PartOfSpeechKind::Particle, PartOfSpeechKind::Preposition),
+        ];
+        for (word, kind) in parse_tagged(TAGGED).unwrap().into_iter().flatten() {
+            let kind = kind.unwrap();
+            let tag = Tag::for_word(kind, &word);
+
+            let universal = UniversalTag::from_label(tag.universal().label()).unwrap();
+            let from_universal = Tag::from(universal).refine(&word).kind().unwrap();
+            assert_eq!(from_universal, kind, "{} from {}", word, universal);
+
+            let penn = PennTag::from_label(tag.penn().label()).unwrap();
+            let from_penn = Tag::from(penn).refine(&word).kind().unwrap();
+            assert!(
+                from_penn == kind || shared.contains(&(from_penn, kind)),
+                "{} read back from {} as {:?}, not {:?}",
+                word,
+                penn,
+                from_penn,
+                kind
+            );
+            assert_eq!(Tag::new(penn, universal).refine(&word).kind(), Some(kind));
+        }
+    }
+}
@@ -39,11 +39,13 @@ pub enum PrepositionCase {
     Genitive,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ConjunctionType {
     Coordinating,
     Subordinating,
     Correlative,
 }
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ConjunctionCategory {
     ComparisonAgreement,
     ContrastDisagreement,
@@ -54,6 +56,7 @@ pub enum ConjunctionCategory {
     Conditional,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Sentiment {
   Excitement,
   Pain,
@@ -61,4 +64,193 @@ pub enum Sentiment {
   Disapproval,
   Approval,
   Neutral,
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Person {
+    First,
+    Second,
+    Third,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tense {
+    Past,
+    Present,
+    Future,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Aspect {
+    Simple,
+    Progressive,
+    Perfect,
+    PerfectProgressive,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mood {
+    Indicative,
+    Subjunctive,
+    Imperative,
+    Conditional,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Voice {
+    Active,
+    Passive,
+}
+
+/// The English a verb is conjugated in: `Modern`, or the `EarlyModern`
+/// English of Shakespeare, with `thou runnest` and `he runneth`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Dialect {
+    Modern,
+    EarlyModern,
+}
+
+/// Everything that selects a verb form: `will have been running` is the
+/// future perfect progressive indicative active, `was taken` the past
+/// simple passive in the first or third person singular. The default is
+/// the present simple indicative active, third person singular, in
+/// Modern English.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VerbFeatures {
+    pub tense: Tense,
+    pub aspect: Aspect,
+    pub mood: Mood,
+    pub voice: Voice,
+    pub person: Person,
+    pub number: Number,
+    pub dialect: Dialect,
+}
+
+impl Default for VerbFeatures {
+    fn default() -> VerbFeatures {
+        VerbFeatures {
+            tense: Tense::Present,
+            aspect: Aspect::Simple,
+            mood: Mood::Indicative,
+            voice: Voice::Active,
+            person: Person::Third,
+            number: Number::Singular,
+            dialect: Dialect::Modern,
+        }
+    }
+}
+
+/// The features of a pronoun. The default is the third person singular.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PronounFeatures {
+    pub person: Person,
+    pub gender: Gender,
+    pub number: Number,
+}
+
+impl Default for PronounFeatures {
+    fn default() -> PronounFeatures {
+        PronounFeatures {
+            person: Person::Third,
+            gender: Gender::Neutral,
+            number: Number::Singular,
+        }
+    }
+}
+
+/// The features of an adjective. The default is a positive adjective
+/// before a singular noun.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdjectiveFeatures {
+    pub gender: Gender,
+    pub number: Number,
+    pub degree: Degree,
+    pub position: Position,
+}
+
+impl Default for AdjectiveFeatures {
+    fn default() -> AdjectiveFeatures {
+        AdjectiveFeatures {
+            gender: Gender::Neutral,
+            number: Number::Singular,
+            degree: Degree::Positive,
+            position: Position::Before,
+        }
+    }
+}
+
+/// The features of an adverb. The default follows the verb it modifies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdverbFeatures {
+    pub modifies: Modifies,
+    pub position: Position,
+}
+
+impl Default for AdverbFeatures {
+    fn default() -> AdverbFeatures {
+        AdverbFeatures {
+            modifies: Modifies::Verb,
+            position: Position::After,
+        }
+    }
+}
+
+/// The features of a preposition. The default takes the accusative.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrepositionFeatures {
+    pub case: PrepositionCase,
+}
+
+impl Default for PrepositionFeatures {
+    fn default() -> PrepositionFeatures {
+        PrepositionFeatures {
+            case: PrepositionCase::Accusative,
+        }
+    }
+}
+
+/// The features of a conjunction. The default is coordinating, as `and`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConjunctionFeatures {
+    pub conjunction_type: ConjunctionType,
+    pub category: ConjunctionCategory,
+}
+
+impl Default for ConjunctionFeatures {
+    fn default() -> ConjunctionFeatures {
+        ConjunctionFeatures {
+            conjunction_type: ConjunctionType::Coordinating,
+            category: ConjunctionCategory::Addition,
+        }
+    }
+}
+
+/// The features of an interjection. The default is neutral.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InterjectionFeatures {
+    pub sentiment: Sentiment,
+}
+
+impl Default for InterjectionFeatures {
+    fn default() -> InterjectionFeatures {
+        InterjectionFeatures {
+            sentiment: Sentiment::Neutral,
+        }
+    }
+}
+
+/// The features of an article. The default is singular.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArticleFeatures {
+    pub gender: Gender,
+    pub number: Number,
+}
+
+impl Default for ArticleFeatures {
+    fn default() -> ArticleFeatures {
+        ArticleFeatures {
+            gender: Gender::Neutral,
+            number: Number::Singular,
+        }
+    }
+}
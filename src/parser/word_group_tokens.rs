@@ -1,6 +1,3 @@
-#![allow(unused)]
-
-
 pub struct Phrase;
 pub struct Clause;
 pub struct Sentence;
@@ -23,4 +20,50 @@ pub struct AdverbClause;
 pub struct GerundClause;
 pub struct InfinitiveClause;
 pub struct RelativeClause;
-pub struct PurposeClause;
\ No newline at end of file
+pub struct PurposeClause;
+
+/// The kinds of word group above, for annotating spans of tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhraseKind {
+    Phrase,
+    Clause,
+    Sentence,
+
+    NounPhrase,
+    VerbPhrase,
+    AdjectivePhrase,
+    AdverbPhrase,
+    PrepositionalPhrase,
+    InfinitivePhrase,
+    GerundPhrase,
+    ParticiplePhrase,
+    InterjectionPhrase,
+
+    DependentClause,
+    NounClause,
+
+    AdjectiveClause,
+    AdverbClause,
+    GerundClause,
+    InfinitiveClause,
+    RelativeClause,
+    PurposeClause,
+}
+
+impl PhraseKind {
+    /// Whether this kind is a clause rather than a phrase.
+    pub fn is_clause(&self) -> bool {
+        matches!(
+            self,
+            PhraseKind::Clause
+                | PhraseKind::DependentClause
+                | PhraseKind::NounClause
+                | PhraseKind::AdjectiveClause
+                | PhraseKind::AdverbClause
+                | PhraseKind::GerundClause
+                | PhraseKind::InfinitiveClause
+                | PhraseKind::RelativeClause
+                | PhraseKind::PurposeClause
+        )
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::VecDeque;
+use std::fs;
+use std::io::Read;
+use std::ops::Range;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::lemmatizer::Lemmatizer;
+use crate::sentences::SentenceStrategy;
+use crate::stemmer;
+use crate::tokenizer::{Token, Tokenizer};
+use crate::vocabulary::Vocabulary;
+
+pub struct Preprocessor {
+    original_text: String, 
+    all_tokens: VecDeque<Token>,
+    unique_words: Vocabulary,
+    sentence_tokens: VecDeque<Range<usize>>,
+    options: PreprocessorOptions,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PreprocessorOptions {
+    pub tokenizer: Tokenizer,
+    pub sentence_strategy: SentenceStrategy,
+    /// Count the vocabulary by Porter2 stem, so `consign` and
+    /// `consigned` are one type.
+    pub stem: bool,
+}
+
+impl Preprocessor {
+    pub fn try_new(file_path: &Path) -> Result<Preprocessor> {
+        Preprocessor::from_path(file_path, PreprocessorOptions::default())
+    }
+
+    pub fn from_path(file_path: &Path, options: PreprocessorOptions) -> Result<Preprocessor> {
+        let bytes = fs::read(file_path)?;
+        Preprocessor::from_bytes(bytes, options)
+    }
+
+    pub fn from_reader(mut reader: impl Read, options: PreprocessorOptions) -> Result<Preprocessor> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+        Preprocessor::from_bytes(bytes, options)
+    }
+
+    pub fn from_text(text: &str, options: PreprocessorOptions) -> Result<Preprocessor> {
+        Preprocessor::process(text.to_string(), options)
+    }
+
+    fn from_bytes(bytes: Vec<u8>, options: PreprocessorOptions) -> Result<Preprocessor> {
+        let original_text = String::from_utf8(bytes).map_err(|error| Error::Encoding {
+            offset: error.utf8_error().valid_up_to(),
+        })?;
+        Preprocessor::process(original_text, options)
+    }
+
+    fn process(original_text: String, options: PreprocessorOptions) -> Result<Preprocessor> {
+        let all_tokens = options.tokenizer.try_tokenize(&original_text)?;
+        let unique_words = Preprocessor::get_unique_words(&all_tokens, options.stem);
+        let sentence_tokens = Preprocessor::get_sentence_tokens(&all_tokens, &options.sentence_strategy);
+
+        Ok(Preprocessor {
+            original_text,
+            all_tokens,
+            unique_words,
+            sentence_tokens,
+            options,
+        })
+    }
+    
+    pub fn original_text(&self) -> &str {
+        &self.original_text
+    }
+
+    pub fn tokens(&self) -> &VecDeque<Token> {
+        &self.all_tokens
+    }
+
+    /// The tokens with every contraction replaced by its expansion from the
+    /// tokenizer's table, so `'Tis` becomes `It` and `is`.
+    pub fn expanded_tokens(&self) -> VecDeque<Token> {
+        self.options.tokenizer.expand(&self.all_tokens)
+    }
+
+    pub fn options(&self) -> &PreprocessorOptions {
+        &self.options
+    }
+
+    pub fn vocabulary(&self) -> &Vocabulary {
+        &self.unique_words
+    }
+
+    /// Lowercase word types in order of first occurrence.
+    pub fn unique_words(&self) -> impl Iterator<Item = &str> {
+        self.unique_words.iter().map(|entry| entry.word())
+    }
+
+    /// The vocabulary counted by lemma rather than by word, so `moves`,
+    /// `moved` and `moving` all count towards `move`.
+    pub fn lemma_vocabulary(&self, lemmatizer: &Lemmatizer) -> Vocabulary {
+        let mut vocabulary = Vocabulary::new();
+        vocabulary.begin_document();
+        let lemmas = lemmatizer.lemmas(&self.all_tokens);
+        for (token, lemma) in self.all_tokens.iter().zip(lemmas) {
+            if let Some(lemma) = lemma {
+                vocabulary.add_token_as(token, &lemma);
+            }
+        }
+        vocabulary
+    }
+
+    /// Lemmas in order of first occurrence.
+    pub fn unique_lemmas(&self, lemmatizer: &Lemmatizer) -> Vec<String> {
+        self.lemma_vocabulary(lemmatizer)
+            .iter()
+            .map(|entry| entry.word().to_string())
+            .collect()
+    }
+
+    /// Sentence boundaries as byte ranges into `original_text`.
+    pub fn sentence_tokens(&self) -> &VecDeque<Range<usize>> {
+        &self.sentence_tokens
+    }
+
+    pub fn sentences(&self) -> impl Iterator<Item = &str> {
+        self.sentence_tokens
+            .iter()
+            .map(|span| &self.original_text[span.clone()])
+    }
+
+    fn get_unique_words(tokens: &VecDeque<Token>, stem: bool) -> Vocabulary {
+        if !stem {
+            return Vocabulary::from_tokens(tokens);
+        }
+        let mut vocabulary = Vocabulary::new();
+        vocabulary.begin_document();
+        for token in tokens.iter().filter(|token| token.is_word()) {
+            vocabulary.add_token_as(token, &stemmer::stem(token.text()));
+        }
+        vocabulary
+    }
+
+    fn get_sentence_tokens(tokens: &VecDeque<Token>, strategy: &SentenceStrategy) -> VecDeque<Range<usize>> {
+        strategy.split(tokens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_from_text() {
+        let preprocessor = Preprocessor::from_text("Two households. Both alike.", PreprocessorOptions::default()).unwrap();
+        assert_eq!(preprocessor.sentences().count(), 2);
+        assert_eq!(preprocessor.vocabulary().len(), 4);
+    }
+
+    #[test]
+    fn counts_lemmas() {
+        let text = "He moves. She moved two households; they keep moving their household.";
+        let preprocessor = Preprocessor::from_text(text, PreprocessorOptions::default()).unwrap();
+        let lemmas = preprocessor.lemma_vocabulary(&Lemmatizer::new());
+        assert_eq!(lemmas.frequency("move"), 3);
+        assert_eq!(lemmas.frequency("household"), 2);
+        assert_eq!(lemmas.frequency("moving"), 0);
+        assert!(lemmas.len() < preprocessor.vocabulary().len());
+        let unique = preprocessor.unique_lemmas(&Lemmatizer::new());
+        assert_eq!(unique[..3], ["he", "move", "she"]);
+    }
+
+    #[test]
+    fn counts_stems_when_asked() {
+        let options = PreprocessorOptions {
+            stem: true,
+            ..PreprocessorOptions::default()
+        };
+        let text = "Consign the consigned consignment.";
+        let preprocessor = Preprocessor::from_text(text, options).unwrap();
+        assert_eq!(preprocessor.vocabulary().frequency("consign"), 3);
+        assert_eq!(preprocessor.unique_words().collect::<Vec<_>>(), ["consign", "the"]);
+    }
+
+    #[test]
+    fn reports_invalid_utf8() {
+        let bytes: &[u8] = b"Romeo\xff";
+        let result = Preprocessor::from_reader(bytes, PreprocessorOptions::default());
+        assert!(matches!(result, Err(Error::Encoding { offset: 5 })));
+    }
+
+    #[test]
+    fn reports_missing_files() {
+        let result = Preprocessor::try_new(Path::new("no_such_file.txt"));
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+}
@@ -0,0 +1,262 @@
+pub mod normalizer;
+
+use std::fmt::{self, Debug};
+
+use crate::document::Document;
+use crate::error::{Error, Result};
+use crate::preprocessor::Preprocessor;
+use crate::sentences::SentenceStrategy;
+use crate::tokenizer::Tokenizer;
+
+/// The steps of a pipeline, in the order they have to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum StageKind {
+    Normalize,
+    Tokenize,
+    SentenceSplit,
+    Tag,
+    Lemmatize,
+    Stem,
+    Chunk,
+    Parse,
+}
+
+impl StageKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            StageKind::Normalize => "normalize",
+            StageKind::Tokenize => "tokenize",
+            StageKind::SentenceSplit => "sentence-split",
+            StageKind::Tag => "tag",
+            StageKind::Lemmatize => "lemmatize",
+            StageKind::Stem => "stem",
+            StageKind::Chunk => "chunk",
+            StageKind::Parse => "parse",
+        }
+    }
+}
+
+/// One step of a `Processor`, annotating a document in place.
+pub trait Stage {
+    fn kind(&self) -> StageKind;
+
+    fn run(&self, document: &mut Document) -> Result<()>;
+
+    fn name(&self) -> &str {
+        self.kind().name()
+    }
+}
+
+impl Stage for Tokenizer {
+    fn kind(&self) -> StageKind {
+        StageKind::Tokenize
+    }
+
+    fn run(&self, document: &mut Document) -> Result<()> {
+        let tokens = self.try_tokenize(document.text())?;
+        document.set_tokens(tokens);
+        Ok(())
+    }
+}
+
+impl Stage for SentenceStrategy {
+    fn kind(&self) -> StageKind {
+        StageKind::SentenceSplit
+    }
+
+    fn run(&self, document: &mut Document) -> Result<()> {
+        let spans = self.split(document.tokens());
+        document.set_sentence_spans(spans);
+        Ok(())
+    }
+}
+
+/// Runs a sequence of stages over the output of a `Preprocessor`.
+///
+/// The document starts out with the preprocessor's text, tokens and
+/// sentences, so an empty processor just repackages them. Stages run in the
+/// order they were added, which has to follow `StageKind` order; a stage
+/// that rewrites the text must be followed by a tokenizer, and a tokenizer
+/// by a sentence splitter, since the later layers would otherwise point at
+/// stale tokens. Lemmatizing, chunking and parsing need tags.
+#[derive(Default)]
+pub struct Processor {
+    stages: Vec<Box<dyn Stage>>,
+}
+
+impl Processor {
+    pub fn new() -> Processor {
+        Processor::default()
+    }
+
+    pub fn add_stage(&mut self, stage: impl Stage + 'static) {
+        self.stages.push(Box::new(stage));
+    }
+
+    pub fn stages(&self) -> impl Iterator<Item = &dyn Stage> {
+        self.stages.iter().map(|stage| stage.as_ref())
+    }
+
+    /// Checks the order of the stages and that every stage has the layers
+    /// it depends on.
+    pub fn validate(&self) -> Result<()> {
+        let kinds: Vec<StageKind> = self.stages.iter().map(|stage| stage.kind()).collect();
+        for (index, stage) in self.stages.iter().enumerate() {
+            let kind = kinds[index];
+            if let Some(previous) = kinds[..index].iter().find(|&&previous| previous > kind) {
+                return Err(pipeline_error(
+                    stage.as_ref(),
+                    format!("must run before the {} stage", previous.name()),
+                ));
+            }
+
+            let later = &kinds[index + 1..];
+            let required_after = match kind {
+                StageKind::Normalize => Some(StageKind::Tokenize),
+                StageKind::Tokenize => Some(StageKind::SentenceSplit),
+                _ => None,
+            };
+            if let Some(required) = required_after.filter(|required| !later.contains(required)) {
+                return Err(pipeline_error(
+                    stage.as_ref(),
+                    format!("must be followed by a {} stage", required.name()),
+                ));
+            }
+
+            let earlier = &kinds[..index];
+            if matches!(
+                kind,
+                StageKind::Lemmatize | StageKind::Chunk | StageKind::Parse
+            ) && !earlier.contains(&StageKind::Tag)
+            {
+                return Err(pipeline_error(
+                    stage.as_ref(),
+                    "needs a tag stage before it",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn process(&self, preprocessor: &Preprocessor) -> Result<Document> {
+        self.validate()?;
+        let mut document = Document::from_preprocessor(preprocessor);
+        for stage in &self.stages {
+            stage.run(&mut document).map_err(|error| match error {
+                Error::Pipeline { .. } => error,
+                error => pipeline_error(stage.as_ref(), error),
+            })?;
+        }
+        Ok(document)
+    }
+}
+
+impl Debug for Processor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.stages.iter().map(|stage| stage.name()))
+            .finish()
+    }
+}
+
+fn pipeline_error(stage: &dyn Stage, message: impl ToString) -> Error {
+    Error::Pipeline {
+        stage: stage.name().to_string(),
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunker::Chunker;
+    use crate::parser::part_of_speech::{Noun, PartOfSpeech};
+    use crate::parser::word_group_tokens::PhraseKind;
+    use crate::preprocessor::PreprocessorOptions;
+    use normalizer::Normalizer;
+
+    struct NounTagger;
+
+    impl Stage for NounTagger {
+        fn kind(&self) -> StageKind {
+            StageKind::Tag
+        }
+
+        fn run(&self, document: &mut Document) -> Result<()> {
+            let words: Vec<usize> = (0..document.tokens().len())
+                .filter(|&index| document.tokens()[index].is_word())
+                .collect();
+            for index in words {
+                let text = document.tokens()[index].text().to_string();
+                if let Some(annotation) = document.annotation_mut(index) {
+                    annotation.set_part_of_speech(<PartOfSpeech as Noun>::new(&text));
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn preprocessor(text: &str) -> Preprocessor {
+        Preprocessor::from_text(text, PreprocessorOptions::default()).unwrap()
+    }
+
+    #[test]
+    fn starts_from_the_preprocessor() {
+        let document = Processor::new()
+            .process(&preprocessor("Two households. Both alike."))
+            .unwrap();
+        assert_eq!(document.sentence_ranges(), [0..4, 5..9]);
+        assert_eq!(document.sentence(0).unwrap().text(), "Two households.");
+    }
+
+    #[test]
+    fn runs_stages_in_order() {
+        let mut processor = Processor::new();
+        processor.add_stage(Normalizer::new());
+        processor.add_stage(Tokenizer::default());
+        processor.add_stage(SentenceStrategy::default());
+        processor.add_stage(NounTagger);
+        processor.add_stage(Chunker);
+
+        let document = processor
+            .process(&preprocessor("\u{2018}Tis so.\r\nAnd so."))
+            .unwrap();
+        assert_eq!(document.text(), "'Tis so.\nAnd so.");
+        assert_eq!(document.tokens()[0].text(), "'Tis");
+        assert_eq!(document.sentence_ranges().len(), 2);
+        assert_eq!(document.phrases().len(), 2);
+        assert_eq!(document.phrases()[0].kind(), PhraseKind::NounPhrase);
+        assert!(document.token(0).unwrap().part_of_speech().is_some());
+        assert!(document.token(3).unwrap().part_of_speech().is_none());
+    }
+
+    #[test]
+    fn rejects_stages_out_of_order() {
+        let mut processor = Processor::new();
+        processor.add_stage(NounTagger);
+        processor.add_stage(Tokenizer::default());
+        processor.add_stage(SentenceStrategy::default());
+        assert!(matches!(
+            processor.validate(),
+            Err(Error::Pipeline { stage, .. }) if stage == "tokenize"
+        ));
+    }
+
+    #[test]
+    fn rejects_missing_dependencies() {
+        let mut processor = Processor::new();
+        processor.add_stage(Normalizer::new());
+        assert!(processor.validate().is_err());
+
+        let mut processor = Processor::new();
+        processor.add_stage(Tokenizer::default());
+        assert!(processor.validate().is_err());
+
+        let mut processor = Processor::new();
+        processor.add_stage(Chunker);
+        assert!(matches!(
+            processor.process(&preprocessor("Both alike.")),
+            Err(Error::Pipeline { stage, .. }) if stage == "chunk"
+        ));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::document::Document;
+use crate::error::Result;
+
+use super::{Stage, StageKind};
+
+/// Rewrites the text of a document into a canonical form before it is
+/// tokenized.
+///
+/// By default it drops byte order marks and zero-width characters, turns
+/// `\r\n` and lone `\r` into `\n`, and folds typographic quotes and
+/// apostrophes into their ASCII forms.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Normalizer {
+    fold_quotes: bool,
+    fold_line_endings: bool,
+}
+
+impl Normalizer {
+    pub fn new() -> Normalizer {
+        Normalizer {
+            fold_quotes: true,
+            fold_line_endings: true,
+        }
+    }
+
+    pub fn set_fold_quotes(&mut self, fold_quotes: bool) {
+        self.fold_quotes = fold_quotes;
+    }
+
+    pub fn set_fold_line_endings(&mut self, fold_line_endings: bool) {
+        self.fold_line_endings = fold_line_endings;
+    }
+
+    pub fn normalize(&self, text: &str) -> String {
+        let mut normalized = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\u{feff}' | '\u{200b}' | '\u{200c}' | '\u{200d}' | '\u{2060}' => {}
+                '\r' if self.fold_line_endings => {
+                    if chars.peek() == Some(&'\n') {
+                        chars.next();
+                    }
+                    normalized.push('\n');
+                }
+                '\u{2018}' | '\u{2019}' | '\u{201a}' | '\u{2032}' if self.fold_quotes => {
+                    normalized.push('\'')
+                }
+                '\u{201c}' | '\u{201d}' | '\u{201e}' | '\u{2033}' if self.fold_quotes => {
+                    normalized.push('"')
+                }
+                c => normalized.push(c),
+            }
+        }
+        normalized
+    }
+}
+
+impl Default for Normalizer {
+    fn default() -> Normalizer {
+        Normalizer::new()
+    }
+}
+
+impl Stage for Normalizer {
+    fn kind(&self) -> StageKind {
+        StageKind::Normalize
+    }
+
+    fn run(&self, document: &mut Document) -> Result<()> {
+        let text = self.normalize(document.text());
+        document.set_text(text);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folds_quotes_and_line_endings() {
+        let normalizer = Normalizer::new();
+        assert_eq!(
+            normalizer.normalize("\u{feff}\u{201c}O Romeo,\u{201d}\r\nshe\u{2019}s\rgone"),
+            "\"O Romeo,\"\nshe's\ngone"
+        );
+
+        let mut normalizer = Normalizer::new();
+        normalizer.set_fold_quotes(false);
+        assert_eq!(normalizer.normalize("she\u{2019}s"), "she\u{2019}s");
+    }
+}
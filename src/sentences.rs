@@ -0,0 +1,357 @@
+pub mod punkt;
+
+use std::collections::{HashSet, VecDeque};
+use std::ops::Range;
+
+use crate::tokenizer::{Token, TokenKind};
+use punkt::PunktSplitter;
+
+const ABBREVIATIONS: [&str; 44] = [
+    "mr", "mrs", "ms", "messrs", "dr", "st", "jr", "sr", "prof", "rev", "hon", "gen", "col",
+    "capt", "lt", "sgt", "cmdr", "gov", "pres", "mt", "ft", "ave", "rd", "co", "corp", "inc",
+    "ltd", "dept", "est", "approx", "vol", "vs", "etc", "cf", "viz", "al", "e.g", "i.e", "a.m",
+    "p.m", "jan", "feb", "aug", "sept",
+];
+
+/// Rule-based sentence boundary detection over a token stream.
+///
+/// A full stop ends a sentence unless it follows a known abbreviation or is
+/// followed by a lowercase word. Whether `;` and `:` end sentences is
+/// configurable and off by default.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentenceSplitter {
+    abbreviations: HashSet<String>,
+    semicolon_ends: bool,
+    colon_ends: bool,
+}
+
+impl SentenceSplitter {
+    pub fn new() -> SentenceSplitter {
+        SentenceSplitter::with_abbreviations(ABBREVIATIONS)
+    }
+
+    /// A splitter that only knows the given abbreviations, written without
+    /// their final full stop (`"mr"`, `"i.e"`).
+    pub fn with_abbreviations<'a>(
+        abbreviations: impl IntoIterator<Item = &'a str>,
+    ) -> SentenceSplitter {
+        SentenceSplitter {
+            abbreviations: abbreviations.into_iter().map(str::to_lowercase).collect(),
+            semicolon_ends: false,
+            colon_ends: false,
+        }
+    }
+
+    pub fn add_abbreviation(&mut self, abbreviation: &str) {
+        let abbreviation = abbreviation.trim_end_matches('.').to_lowercase();
+        self.abbreviations.insert(abbreviation);
+    }
+
+    pub fn is_abbreviation(&self, word: &str) -> bool {
+        self.abbreviations.contains(&word.to_lowercase())
+    }
+
+    pub fn set_semicolon_ends(&mut self, semicolon_ends: bool) {
+        self.semicolon_ends = semicolon_ends;
+    }
+
+    pub fn set_colon_ends(&mut self, colon_ends: bool) {
+        self.colon_ends = colon_ends;
+    }
+
+    /// Splits `tokens` into sentences, returned as byte ranges into the text
+    /// the tokens were taken from. Ranges start at the first and end at the
+    /// last non-whitespace token of each sentence.
+    pub fn split(&self, tokens: &VecDeque<Token>) -> VecDeque<Range<usize>> {
+        segment(
+            tokens,
+            |token| self.is_terminator(token),
+            |tokens, index| self.ends_sentence(tokens, index),
+        )
+    }
+
+    fn is_terminator(&self, token: &Token) -> bool {
+        if token.kind() != TokenKind::Punctuation {
+            return false;
+        }
+        match token.text() {
+            "." | "!" | "?" => true,
+            ";" => self.semicolon_ends,
+            ":" => self.colon_ends,
+            text => is_ellipsis(text),
+        }
+    }
+
+    /// Whether the terminator at `index` really ends its sentence.
+    fn ends_sentence(&self, tokens: &VecDeque<Token>, index: usize) -> bool {
+        let token = &tokens[index];
+
+        if token.text() == "." {
+            if let Some(previous) = previous_word(tokens, index) {
+                if previous.is_word() && self.is_abbreviation(previous.text()) {
+                    return false;
+                }
+            }
+        }
+
+        if token.text() == "." || is_ellipsis(token.text()) {
+            if let Some(next) = next_word(tokens, index) {
+                if next.text().starts_with(char::is_lowercase) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+impl Default for SentenceSplitter {
+    fn default() -> SentenceSplitter {
+        SentenceSplitter::new()
+    }
+}
+
+/// Alternative ways of finding sentence boundaries.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SentenceStrategy {
+    Rules(SentenceSplitter),
+    Punkt(PunktSplitter),
+}
+
+impl SentenceStrategy {
+    pub fn split(&self, tokens: &VecDeque<Token>) -> VecDeque<Range<usize>> {
+        match self {
+            SentenceStrategy::Rules(splitter) => splitter.split(tokens),
+            SentenceStrategy::Punkt(splitter) => splitter.split(tokens),
+        }
+    }
+}
+
+impl Default for SentenceStrategy {
+    fn default() -> SentenceStrategy {
+        SentenceStrategy::Rules(SentenceSplitter::new())
+    }
+}
+
+/// Groups `tokens` into sentences, calling `ends_sentence` with the index of
+/// every token `is_terminator` accepts.
+///
+/// Closing quotes and brackets directly after an accepted terminator are
+/// kept with its sentence, terminators inside an open bracket are ignored,
+/// and a blank line always ends a sentence.
+pub(crate) fn segment(
+    tokens: &VecDeque<Token>,
+    is_terminator: impl Fn(&Token) -> bool,
+    ends_sentence: impl Fn(&VecDeque<Token>, usize) -> bool,
+) -> VecDeque<Range<usize>> {
+    let mut sentences = VecDeque::new();
+    let mut start: Option<usize> = None;
+    let mut end = 0;
+    let mut depth = 0usize;
+
+    let mut index = 0;
+    while index < tokens.len() {
+        let token = &tokens[index];
+        index += 1;
+
+        if token.is_whitespace() {
+            if is_paragraph_break(token) {
+                if let Some(start) = start.take() {
+                    sentences.push_back(start..end);
+                }
+                depth = 0;
+            }
+            continue;
+        }
+
+        start.get_or_insert(token.start());
+        end = token.end();
+        depth = track_depth(token, depth);
+
+        if !is_terminator(token) || !ends_sentence(tokens, index - 1) {
+            continue;
+        }
+
+        let mut closed_bracket = false;
+        while let Some(next) = tokens.get(index) {
+            if next.start() != end || !(is_terminator(next) || is_closer(next)) {
+                break;
+            }
+            closed_bracket |= depth > 0 && track_depth(next, depth) < depth;
+            depth = track_depth(next, depth);
+            end = next.end();
+            index += 1;
+        }
+
+        if depth == 0 || closed_bracket {
+            if let Some(start) = start.take() {
+                sentences.push_back(start..end);
+            }
+            depth = 0;
+        }
+    }
+
+    if let Some(start) = start {
+        sentences.push_back(start..end);
+    }
+    sentences
+}
+
+/// The word token that the terminator at `index` is attached to, if any.
+pub(crate) fn previous_word(tokens: &VecDeque<Token>, index: usize) -> Option<&Token> {
+    let token = &tokens[index];
+    let previous = tokens.get(index.checked_sub(1)?)?;
+    if previous.end() == token.start() && previous.kind() != TokenKind::Punctuation {
+        Some(previous)
+    } else {
+        None
+    }
+}
+
+/// The first word after the terminator at `index`, skipping whitespace and
+/// closing punctuation. `None` if anything else comes first.
+pub(crate) fn next_word(tokens: &VecDeque<Token>, index: usize) -> Option<&Token> {
+    tokens
+        .iter()
+        .skip(index + 1)
+        .find(|next| !next.is_whitespace() && !is_closer(next))
+        .filter(|next| next.is_word())
+}
+
+pub(crate) fn is_ellipsis(text: &str) -> bool {
+    text == "\u{2026}" || (text.len() >= 2 && text.chars().all(|c| c == '.'))
+}
+
+pub(crate) fn is_paragraph_break(token: &Token) -> bool {
+    token.text().matches('\n').count() >= 2
+}
+
+/// Punctuation that may follow a terminator and still belong to the
+/// sentence: closing quotes and brackets, and dashes.
+pub(crate) fn is_closer(token: &Token) -> bool {
+    if token.kind() != TokenKind::Punctuation {
+        return false;
+    }
+    let text = token.text();
+    matches!(
+        text,
+        "\"" | "'" | "\u{201d}" | "\u{2019}" | "\u{bb}" | ")" | "]" | "}"
+    ) || text.starts_with(['\u{2014}', '\u{2013}'])
+        || (text.len() >= 2 && text.chars().all(|c| c == '-'))
+}
+
+fn track_depth(token: &Token, depth: usize) -> usize {
+    match token.text() {
+        "(" | "[" | "{" => depth + 1,
+        ")" | "]" | "}" => depth.saturating_sub(1),
+        _ => depth,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+
+    fn sentences<'a>(splitter: &SentenceSplitter, text: &'a str) -> Vec<&'a str> {
+        let tokens = Tokenizer::default().tokenize(text);
+        splitter
+            .split(&tokens)
+            .into_iter()
+            .map(|span| &text[span])
+            .collect()
+    }
+
+    #[test]
+    fn splits_on_terminators() {
+        let text =
+            "I strike quickly, being moved. But thou art not quickly moved to strike! Art thou?";
+        assert_eq!(
+            sentences(&SentenceSplitter::new(), text),
+            [
+                "I strike quickly, being moved.",
+                "But thou art not quickly moved to strike!",
+                "Art thou?"
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_abbreviations() {
+        let text = "Mr. Capulet met Dr. Lawrence at St. Peter's, i.e. the church. He left.";
+        assert_eq!(
+            sentences(&SentenceSplitter::new(), text),
+            [
+                "Mr. Capulet met Dr. Lawrence at St. Peter's, i.e. the church.",
+                "He left."
+            ]
+        );
+    }
+
+    #[test]
+    fn learns_new_abbreviations() {
+        let mut splitter = SentenceSplitter::with_abbreviations([]);
+        assert_eq!(sentences(&splitter, "Ask Fr. Lawrence.").len(), 2);
+        splitter.add_abbreviation("Fr.");
+        assert_eq!(sentences(&splitter, "Ask Fr. Lawrence.").len(), 1);
+    }
+
+    #[test]
+    fn semicolons_and_colons_are_configurable() {
+        let text = "I will be civil with the maids; I will cut off their heads: so.";
+        let mut splitter = SentenceSplitter::new();
+        assert_eq!(sentences(&splitter, text).len(), 1);
+
+        splitter.set_semicolon_ends(true);
+        assert_eq!(
+            sentences(&splitter, text),
+            [
+                "I will be civil with the maids;",
+                "I will cut off their heads: so."
+            ]
+        );
+
+        splitter.set_colon_ends(true);
+        assert_eq!(sentences(&splitter, text).len(), 3);
+    }
+
+    #[test]
+    fn keeps_closing_quotes_and_brackets() {
+        let text = "[Chorus exits.] \"Shake,\" quoth the dovehouse. \"Dost thou fall?\" And so.";
+        assert_eq!(
+            sentences(&SentenceSplitter::new(), text),
+            [
+                "[Chorus exits.]",
+                "\"Shake,\" quoth the dovehouse.",
+                "\"Dost thou fall?\"",
+                "And so."
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_split_inside_brackets() {
+        let text = "He came (it was late. Very late) and left. Then slept.";
+        assert_eq!(
+            sentences(&SentenceSplitter::new(), text),
+            ["He came (it was late. Very late) and left.", "Then slept."]
+        );
+    }
+
+    #[test]
+    fn ends_sentences_at_blank_lines() {
+        let text = "ACT 1\n=====\n\nScene 1\n=======";
+        assert_eq!(
+            sentences(&SentenceSplitter::new(), text),
+            ["ACT 1\n=====", "Scene 1\n======="]
+        );
+    }
+
+    #[test]
+    fn spans_point_into_the_original_text() {
+        let text = "  Away! Go.";
+        let tokens = Tokenizer::default().tokenize(text);
+        let spans = SentenceSplitter::new().split(&tokens);
+        assert_eq!(spans, [2..7, 8..11]);
+    }
+}
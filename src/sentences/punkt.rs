@@ -0,0 +1,647 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::{self, Display};
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+use super::{is_closer, is_ellipsis, is_paragraph_break, previous_word, segment};
+use crate::error::{Error, Result};
+use crate::tokenizer::{Token, TokenKind};
+
+const ABBREVIATION_THRESHOLD: f64 = 0.3;
+const COLLOCATION_THRESHOLD: f64 = 7.88;
+const SENTENCE_STARTER_THRESHOLD: f64 = 30.0;
+const NUMBER_TYPE: &str = "##number##";
+
+const BEGINNING_UPPER: u8 = 1 << 1;
+const MIDDLE_UPPER: u8 = 1 << 2;
+const UNKNOWN_UPPER: u8 = 1 << 3;
+const BEGINNING_LOWER: u8 = 1 << 4;
+const MIDDLE_LOWER: u8 = 1 << 5;
+const UNKNOWN_LOWER: u8 = 1 << 6;
+const UPPER: u8 = BEGINNING_UPPER | MIDDLE_UPPER | UNKNOWN_UPPER;
+const LOWER: u8 = BEGINNING_LOWER | MIDDLE_LOWER | UNKNOWN_LOWER;
+
+/// What a Punkt model has learned from its training corpus.
+///
+/// `ortho_context` records, per lowercase word type, in which positions
+/// (sentence-initial, sentence-internal or unknown) the word was seen
+/// capitalised or in lowercase.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PunktParameters {
+    abbreviations: HashSet<String>,
+    collocations: HashSet<(String, String)>,
+    sentence_starters: HashSet<String>,
+    ortho_context: HashMap<String, u8>,
+}
+
+impl PunktParameters {
+    pub fn is_abbreviation(&self, word: &str) -> bool {
+        self.abbreviations.contains(&word.to_lowercase())
+    }
+
+    pub fn is_collocation(&self, first: &str, second: &str) -> bool {
+        let pair = (first.to_lowercase(), second.to_lowercase());
+        self.collocations.contains(&pair)
+    }
+
+    pub fn is_sentence_starter(&self, word: &str) -> bool {
+        self.sentence_starters.contains(&word.to_lowercase())
+    }
+
+    pub fn abbreviations(&self) -> &HashSet<String> {
+        &self.abbreviations
+    }
+
+    pub fn collocations(&self) -> &HashSet<(String, String)> {
+        &self.collocations
+    }
+
+    pub fn sentence_starters(&self) -> &HashSet<String> {
+        &self.sentence_starters
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        Ok(fs::write(path, self.to_string())?)
+    }
+
+    pub fn load(path: &Path) -> Result<PunktParameters> {
+        PunktParameters::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Reads parameters in the format written by `Display`: one
+    /// tab-separated entry per line, blank lines and `#` comments ignored.
+    pub fn parse(text: &str) -> Result<PunktParameters> {
+        let mut parameters = PunktParameters::default();
+
+        for (number, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split('\t').collect();
+            match fields.as_slice() {
+                ["abbreviation", word] => {
+                    parameters.abbreviations.insert(word.to_string());
+                }
+                ["collocation", first, second] => {
+                    parameters
+                        .collocations
+                        .insert((first.to_string(), second.to_string()));
+                }
+                ["sentence_starter", word] => {
+                    parameters.sentence_starters.insert(word.to_string());
+                }
+                ["ortho", word, flags] => {
+                    let flags = flags.parse().map_err(|_| invalid_line(number, line))?;
+                    parameters.ortho_context.insert(word.to_string(), flags);
+                }
+                _ => return Err(invalid_line(number, line)),
+            }
+        }
+        Ok(parameters)
+    }
+
+    fn ortho_flags(&self, word: &str) -> u8 {
+        self.ortho_context.get(word).copied().unwrap_or(0)
+    }
+
+    /// Whether `token` looks like it starts a sentence judging by how its
+    /// type was capitalised in training, or `None` if undecided.
+    fn ortho_heuristic(&self, token: &Token) -> Option<bool> {
+        if matches!(token.text(), ";" | ":" | "," | "." | "!" | "?") {
+            return Some(false);
+        }
+        let flags = self.ortho_flags(&word_type(token));
+        if starts_upper(token) && flags & LOWER != 0 && flags & MIDDLE_UPPER == 0 {
+            return Some(true);
+        }
+        if starts_lower(token) && (flags & UPPER != 0 || flags & BEGINNING_LOWER == 0) {
+            return Some(false);
+        }
+        None
+    }
+}
+
+impl Display for PunktParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut abbreviations: Vec<&String> = self.abbreviations.iter().collect();
+        abbreviations.sort();
+        for word in abbreviations {
+            writeln!(f, "abbreviation\t{}", word)?;
+        }
+
+        let mut collocations: Vec<&(String, String)> = self.collocations.iter().collect();
+        collocations.sort();
+        for (first, second) in collocations {
+            writeln!(f, "collocation\t{}\t{}", first, second)?;
+        }
+
+        let mut starters: Vec<&String> = self.sentence_starters.iter().collect();
+        starters.sort();
+        for word in starters {
+            writeln!(f, "sentence_starter\t{}", word)?;
+        }
+
+        let mut ortho: Vec<(&String, &u8)> = self.ortho_context.iter().collect();
+        ortho.sort();
+        for (word, flags) in ortho {
+            writeln!(f, "ortho\t{}\t{}", word, flags)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct TypeCount {
+    plain: u64,
+    with_period: u64,
+}
+
+/// Learns `PunktParameters` from unlabelled text in the manner of Kiss and
+/// Strunk's Punkt: abbreviations are types that strongly prefer a following
+/// full stop, collocations are numbers or initials that keep company with
+/// the word after their full stop, and sentence starters are words that
+/// follow sentence breaks far more often than chance.
+#[derive(Debug, Clone, Default)]
+pub struct PunktTrainer {
+    types: HashMap<String, TypeCount>,
+    total: u64,
+    period_final: u64,
+    sentence_breaks: u64,
+    collocation_counts: HashMap<(String, String), u64>,
+    starter_counts: HashMap<String, u64>,
+    parameters: PunktParameters,
+}
+
+impl PunktTrainer {
+    pub fn new() -> PunktTrainer {
+        PunktTrainer::default()
+    }
+
+    /// Adds one batch of tokens to the training data. Can be called
+    /// repeatedly before `finish`.
+    pub fn train(&mut self, tokens: &VecDeque<Token>) {
+        let units = units(tokens);
+
+        for unit in &units {
+            if unit.token.kind() == TokenKind::Punctuation {
+                continue;
+            }
+            let count = self.types.entry(unit.word_type.clone()).or_default();
+            if unit.period_final {
+                count.with_period += 1;
+                self.period_final += 1;
+            } else {
+                count.plain += 1;
+            }
+            self.total += 1;
+        }
+        self.reclassify_abbreviations();
+
+        let breaks: Vec<bool> = units
+            .iter()
+            .map(|unit| self.first_pass_break(unit))
+            .collect();
+        self.collect_ortho_context(&units, &breaks);
+
+        for (index, pair) in units.windows(2).enumerate() {
+            let (first, second) = (&pair[0], &pair[1]);
+            if !breaks[index] || !second.token.is_word() {
+                continue;
+            }
+            self.sentence_breaks += 1;
+            *self
+                .starter_counts
+                .entry(second.word_type.clone())
+                .or_default() += 1;
+            if first.token.kind() == TokenKind::Number || is_initial(first) {
+                let key = (first.word_type.clone(), second.word_type.clone());
+                *self.collocation_counts.entry(key).or_default() += 1;
+            }
+        }
+    }
+
+    pub fn finish(mut self) -> PunktParameters {
+        // Collocations ending in a sentence starter are skipped, so the
+        // starters have to be known first.
+        self.find_sentence_starters();
+        self.find_collocations();
+        self.parameters
+    }
+
+    fn reclassify_abbreviations(&mut self) {
+        for (word_type, count) in &self.types {
+            if count.with_period == 0 || !word_type.chars().any(char::is_alphabetic) {
+                continue;
+            }
+            let periods = word_type.matches('.').count() as f64 + 1.0;
+            let non_periods = (word_type.chars().count() as f64 - periods + 1.0).max(1.0);
+
+            let likelihood = dunning_log_likelihood(
+                count.plain + count.with_period,
+                self.period_final,
+                count.with_period,
+                self.total,
+            );
+            let length_factor = (-non_periods).exp();
+            let penalty = non_periods.powf(-(count.plain as f64));
+            let score = likelihood * length_factor * periods * penalty;
+
+            if score >= ABBREVIATION_THRESHOLD {
+                self.parameters.abbreviations.insert(word_type.clone());
+            } else {
+                self.parameters.abbreviations.remove(word_type);
+            }
+        }
+    }
+
+    fn first_pass_break(&self, unit: &Unit) -> bool {
+        match unit.token.text() {
+            "!" | "?" | "." => true,
+            _ => unit.period_final && !self.parameters.abbreviations.contains(&unit.word_type),
+        }
+    }
+
+    fn collect_ortho_context(&mut self, units: &[Unit], breaks: &[bool]) {
+        let mut context = Context::Internal;
+
+        for (unit, &is_break) in units.iter().zip(breaks) {
+            if unit.paragraph_start && context != Context::Unknown {
+                context = Context::Initial;
+            }
+            if unit.line_start && context == Context::Internal {
+                context = Context::Unknown;
+            }
+
+            let flag = match (context, starts_upper(unit.token), starts_lower(unit.token)) {
+                (Context::Initial, true, _) => BEGINNING_UPPER,
+                (Context::Internal, true, _) => MIDDLE_UPPER,
+                (Context::Unknown, true, _) => UNKNOWN_UPPER,
+                (Context::Initial, _, true) => BEGINNING_LOWER,
+                (Context::Internal, _, true) => MIDDLE_LOWER,
+                (Context::Unknown, _, true) => UNKNOWN_LOWER,
+                _ => 0,
+            };
+            if flag != 0 {
+                *self
+                    .parameters
+                    .ortho_context
+                    .entry(unit.word_type.clone())
+                    .or_default() |= flag;
+            }
+
+            context = if is_break {
+                match unit.token.kind() == TokenKind::Number || is_initial(unit) {
+                    true => Context::Unknown,
+                    false => Context::Initial,
+                }
+            } else if is_ellipsis(unit.token.text()) || unit.period_final {
+                Context::Unknown
+            } else {
+                Context::Internal
+            };
+        }
+    }
+
+    fn find_collocations(&mut self) {
+        for ((first, second), &count) in &self.collocation_counts {
+            if self.parameters.sentence_starters.contains(second) {
+                continue;
+            }
+            let first_count = self.type_total(first);
+            let second_count = self.type_total(second);
+            if first_count <= 1 || second_count <= 1 || count <= 1 {
+                continue;
+            }
+            if count > first_count.min(second_count) {
+                continue;
+            }
+
+            let likelihood =
+                collocation_log_likelihood(first_count, second_count, count, self.total);
+            let expected = self.total as f64 / first_count as f64;
+            if likelihood >= COLLOCATION_THRESHOLD && expected > second_count as f64 / count as f64
+            {
+                let pair = (first.clone(), second.clone());
+                self.parameters.collocations.insert(pair);
+            }
+        }
+    }
+
+    fn find_sentence_starters(&mut self) {
+        for (word_type, &count) in &self.starter_counts {
+            let type_count = self.type_total(word_type);
+            if type_count < count || self.sentence_breaks == 0 {
+                continue;
+            }
+
+            let likelihood =
+                collocation_log_likelihood(self.sentence_breaks, type_count, count, self.total);
+            let expected = self.total as f64 / self.sentence_breaks as f64;
+            if likelihood >= SENTENCE_STARTER_THRESHOLD
+                && expected > type_count as f64 / count as f64
+            {
+                self.parameters.sentence_starters.insert(word_type.clone());
+            }
+        }
+    }
+
+    fn type_total(&self, word_type: &str) -> u64 {
+        self.types
+            .get(word_type)
+            .map_or(0, |count| count.plain + count.with_period)
+    }
+}
+
+/// Splits sentences using trained `PunktParameters`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PunktSplitter {
+    parameters: PunktParameters,
+}
+
+impl PunktSplitter {
+    pub fn new(parameters: PunktParameters) -> PunktSplitter {
+        PunktSplitter { parameters }
+    }
+
+    pub fn parameters(&self) -> &PunktParameters {
+        &self.parameters
+    }
+
+    pub fn split(&self, tokens: &VecDeque<Token>) -> VecDeque<Range<usize>> {
+        segment(
+            tokens,
+            |token| {
+                token.kind() == TokenKind::Punctuation
+                    && (matches!(token.text(), "." | "!" | "?") || is_ellipsis(token.text()))
+            },
+            |tokens, index| self.ends_sentence(tokens, index),
+        )
+    }
+
+    fn ends_sentence(&self, tokens: &VecDeque<Token>, index: usize) -> bool {
+        let token = &tokens[index];
+        let next = tokens
+            .iter()
+            .skip(index + 1)
+            .find(|next| !next.is_whitespace() && !is_closer(next));
+        let next = match next {
+            Some(next) => next,
+            None => return true,
+        };
+
+        if is_ellipsis(token.text()) {
+            return self.abbreviation_ends_sentence(next);
+        }
+        if token.text() != "." {
+            return true;
+        }
+        let previous = match previous_word(tokens, index) {
+            Some(previous) => previous,
+            None => return true,
+        };
+
+        let previous_type = word_type(previous);
+        let next_type = word_type(next);
+        if self.parameters.is_collocation(&previous_type, &next_type) {
+            return false;
+        }
+
+        let initial = previous.is_word() && previous.text().chars().count() == 1;
+        if self.parameters.is_abbreviation(&previous_type) && !initial {
+            return self.abbreviation_ends_sentence(next);
+        }
+
+        if initial || previous.kind() == TokenKind::Number {
+            match self.parameters.ortho_heuristic(next) {
+                Some(false) => return false,
+                None if initial
+                    && starts_upper(next)
+                    && self.parameters.ortho_flags(&next_type) & LOWER == 0 =>
+                {
+                    return false;
+                }
+                _ => {}
+            }
+        }
+        true
+    }
+
+    /// An abbreviation or ellipsis only ends a sentence when the next word
+    /// is known to start sentences.
+    fn abbreviation_ends_sentence(&self, next: &Token) -> bool {
+        if self.parameters.ortho_heuristic(next) == Some(true) {
+            return true;
+        }
+        starts_upper(next) && self.parameters.is_sentence_starter(&word_type(next))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Context {
+    Initial,
+    Internal,
+    Unknown,
+}
+
+/// A non-whitespace token annotated for training, with any full stop
+/// directly after a word folded into it.
+struct Unit<'a> {
+    token: &'a Token,
+    word_type: String,
+    period_final: bool,
+    line_start: bool,
+    paragraph_start: bool,
+}
+
+fn units(tokens: &VecDeque<Token>) -> Vec<Unit<'_>> {
+    let mut units: Vec<Unit> = Vec::new();
+    let mut line_start = true;
+    let mut paragraph_start = true;
+
+    for token in tokens {
+        if token.is_whitespace() {
+            line_start |= token.text().contains('\n');
+            paragraph_start |= is_paragraph_break(token);
+            continue;
+        }
+
+        if token.text() == "." {
+            if let Some(previous) = units.last_mut() {
+                let attached = previous.token.end() == token.start()
+                    && previous.token.kind() != TokenKind::Punctuation
+                    && !previous.period_final;
+                if attached {
+                    previous.period_final = true;
+                    continue;
+                }
+            }
+        }
+
+        units.push(Unit {
+            token,
+            word_type: word_type(token),
+            period_final: false,
+            line_start,
+            paragraph_start,
+        });
+        line_start = false;
+        paragraph_start = false;
+    }
+    units
+}
+
+fn word_type(token: &Token) -> String {
+    match token.kind() {
+        TokenKind::Number => NUMBER_TYPE.to_string(),
+        _ => token.text().to_lowercase(),
+    }
+}
+
+fn is_initial(unit: &Unit) -> bool {
+    unit.period_final && unit.token.is_word() && unit.token.text().chars().count() == 1
+}
+
+fn starts_upper(token: &Token) -> bool {
+    token.text().starts_with(char::is_uppercase)
+}
+
+fn starts_lower(token: &Token) -> bool {
+    token.text().starts_with(char::is_lowercase)
+}
+
+fn invalid_line(number: usize, line: &str) -> Error {
+    Error::Parse {
+        line: number + 1,
+        message: format!("invalid Punkt parameter {:?}", line),
+    }
+}
+
+/// `x * ln(y)`, taken as zero when `x` is zero so that empty counts do not
+/// produce NaN.
+fn x_ln_y(x: f64, y: f64) -> f64 {
+    if x == 0.0 {
+        0.0
+    } else {
+        x * y.ln()
+    }
+}
+
+/// Dunning's log-likelihood that a type is followed by a full stop more
+/// often than the overall full-stop rate, against an expected rate of 0.99.
+fn dunning_log_likelihood(count_a: u64, count_b: u64, count_ab: u64, total: u64) -> f64 {
+    let (a, b, ab, n) = (
+        count_a as f64,
+        count_b as f64,
+        count_ab as f64,
+        total as f64,
+    );
+    let p1 = b / n;
+    let p2 = 0.99;
+
+    let null_hypothesis = x_ln_y(ab, p1) + x_ln_y(a - ab, 1.0 - p1);
+    let alternative_hypothesis = x_ln_y(ab, p2) + x_ln_y(a - ab, 1.0 - p2);
+    -2.0 * (null_hypothesis - alternative_hypothesis)
+}
+
+/// Log-likelihood ratio that `a` and `b` co-occur `ab` times by more than
+/// chance, as used for collocations and sentence starters.
+fn collocation_log_likelihood(count_a: u64, count_b: u64, count_ab: u64, total: u64) -> f64 {
+    let (a, b, ab, n) = (
+        count_a as f64,
+        count_b as f64,
+        count_ab as f64,
+        total as f64,
+    );
+    let p = b / n;
+    let p1 = ab / a;
+    let p2 = (b - ab) / (n - a);
+
+    let summand1 = x_ln_y(ab, p) + x_ln_y(a - ab, 1.0 - p);
+    let summand2 = x_ln_y(b - ab, p) + x_ln_y(n - a - b + ab, 1.0 - p);
+    let summand3 = x_ln_y(ab, p1) + x_ln_y(a - ab, 1.0 - p1);
+    let summand4 = x_ln_y(b - ab, p2) + x_ln_y(n - a - b + ab, 1.0 - p2);
+    -2.0 * (summand1 + summand2 - summand3 - summand4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+
+    fn trained() -> PunktParameters {
+        let text = include_str!("../../romeo_and_juliet.txt");
+        let mut trainer = PunktTrainer::new();
+        trainer.train(&Tokenizer::default().tokenize(text));
+        trainer.finish()
+    }
+
+    #[test]
+    fn learns_from_the_corpus() {
+        let parameters = trained();
+        assert!(!parameters.is_abbreviation("love"));
+        assert!(!parameters.is_abbreviation("romeo"));
+        assert!(!parameters.ortho_context.is_empty());
+    }
+
+    #[test]
+    fn learns_abbreviations() {
+        let text = "I met Mr. Smith today. He said hello. Then Mr. Jones came. \
+                    Mr. Brown left early. We ate. Mr. Green stayed. It rained. "
+            .repeat(10);
+        let mut trainer = PunktTrainer::new();
+        trainer.train(&Tokenizer::default().tokenize(&text));
+        let parameters = trainer.finish();
+        assert!(parameters.is_abbreviation("mr"));
+        assert!(!parameters.is_abbreviation("today"));
+
+        let tokens = Tokenizer::default().tokenize("I met Mr. Smith today. He left.");
+        let spans = PunktSplitter::new(parameters).split(&tokens);
+        assert_eq!(spans.len(), 2);
+    }
+
+    #[test]
+    fn does_not_learn_collocations_with_sentence_starters() {
+        let text = "The score was 3. However we won. They ran 5. However it rained. \
+                    We sang loudly today. However you stayed. I counted to 7. \
+                    However he slept. "
+            .repeat(10);
+        let mut trainer = PunktTrainer::new();
+        trainer.train(&Tokenizer::default().tokenize(&text));
+        let parameters = trainer.finish();
+        assert!(parameters.is_sentence_starter("however"));
+        assert!(!parameters.is_collocation(NUMBER_TYPE, "however"));
+    }
+
+    #[test]
+    fn splits_like_the_rules_on_plain_text() {
+        let text = "SAMPSON  I strike quickly, being moved.\n\nGREGORY  But thou art not quickly moved to strike! Art thou?";
+        let tokens = Tokenizer::default().tokenize(text);
+        let sentences: Vec<&str> = PunktSplitter::new(trained())
+            .split(&tokens)
+            .into_iter()
+            .map(|span| &text[span])
+            .collect();
+        assert_eq!(
+            sentences,
+            [
+                "SAMPSON  I strike quickly, being moved.",
+                "GREGORY  But thou art not quickly moved to strike!",
+                "Art thou?"
+            ]
+        );
+    }
+
+    #[test]
+    fn round_trips_through_text() {
+        let parameters = trained();
+        let parsed = PunktParameters::parse(&parameters.to_string()).unwrap();
+        assert_eq!(parsed, parameters);
+    }
+
+    #[test]
+    fn rejects_malformed_parameters() {
+        assert!(PunktParameters::parse("abbreviation\tmr\nbogus line").is_err());
+        assert!(PunktParameters::parse("ortho\tthe\tmany").is_err());
+    }
+}
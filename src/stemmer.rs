@@ -0,0 +1,457 @@
+//! The Porter2 stemmer, also known as the Snowball English stemmer.
+//!
+//! Stemming strips suffixes by rule, without a dictionary, so related
+//! forms share a stem: `consign`, `consigned` and `consignment` all give
+//! `consign`. Stems need not be words (`happy` gives `happi`); use the
+//! [`Lemmatizer`](crate::Lemmatizer) for dictionary forms.
+
+use crate::document::Document;
+use crate::error::Result;
+use crate::processor::{Stage, StageKind};
+use crate::tokenizer::contractions::is_apostrophe;
+
+/// Words stemmed as a whole, before any rule applies.
+const EXCEPTIONS: [(&str, &str); 18] = [
+    ("skis", "ski"),
+    ("skies", "sky"),
+    ("dying", "die"),
+    ("lying", "lie"),
+    ("tying", "tie"),
+    ("idly", "idl"),
+    ("gently", "gentl"),
+    ("ugly", "ugli"),
+    ("early", "earli"),
+    ("only", "onli"),
+    ("singly", "singl"),
+    ("sky", "sky"),
+    ("news", "news"),
+    ("howe", "howe"),
+    ("atlas", "atlas"),
+    ("cosmos", "cosmos"),
+    ("bias", "bias"),
+    ("andes", "andes"),
+];
+
+/// Words left alone once their plural `-s` is gone.
+const INVARIANT_AFTER_PLURAL: [&str; 8] = [
+    "inning", "outing", "canning", "herring", "earring", "proceed", "exceed", "succeed",
+];
+
+/// Prefixes that count as the whole first region.
+const PREFIXES: [&str; 3] = ["gener", "commun", "arsen"];
+
+const STEP_2: [(&str, &str); 24] = [
+    ("ization", "ize"),
+    ("ational", "ate"),
+    ("fulness", "ful"),
+    ("ousness", "ous"),
+    ("iveness", "ive"),
+    ("tional", "tion"),
+    ("biliti", "ble"),
+    ("lessli", "less"),
+    ("entli", "ent"),
+    ("ation", "ate"),
+    ("alism", "al"),
+    ("aliti", "al"),
+    ("ousli", "ous"),
+    ("iviti", "ive"),
+    ("fulli", "ful"),
+    ("enci", "ence"),
+    ("anci", "ance"),
+    ("abli", "able"),
+    ("izer", "ize"),
+    ("ator", "ate"),
+    ("alli", "al"),
+    ("bli", "ble"),
+    ("ogi", "og"),
+    ("li", ""),
+];
+
+const STEP_3: [(&str, &str); 9] = [
+    ("ational", "ate"),
+    ("tional", "tion"),
+    ("alize", "al"),
+    ("icate", "ic"),
+    ("iciti", "ic"),
+    ("ative", ""),
+    ("ical", "ic"),
+    ("ness", ""),
+    ("ful", ""),
+];
+
+const STEP_4: [&str; 18] = [
+    "ement", "ance", "ence", "able", "ible", "ment", "ant", "ent", "ism", "ate", "iti", "ous",
+    "ive", "ize", "ion", "al", "er", "ic",
+];
+
+/// The Porter2 stem of `word`, in lowercase.
+pub fn stem(word: &str) -> String {
+    let lowercase: String = word
+        .chars()
+        .map(|c| if is_apostrophe(c) { '\'' } else { c })
+        .collect::<String>()
+        .to_lowercase();
+    let word = lowercase.trim_start_matches('\'');
+    if word.chars().count() <= 2 {
+        return word.to_string();
+    }
+    if let Some(&(_, stem)) = EXCEPTIONS.iter().find(|(exception, _)| *exception == word) {
+        return stem.to_string();
+    }
+
+    let mut stemmer = Stemming::new(word);
+    stemmer.step_0();
+    stemmer.step_1a();
+    if INVARIANT_AFTER_PLURAL.contains(&stemmer.to_string().as_str()) {
+        return stemmer.to_string();
+    }
+    stemmer.step_1b();
+    stemmer.step_1c();
+    stemmer.step_2();
+    stemmer.step_3();
+    stemmer.step_4();
+    stemmer.step_5();
+    stemmer.to_string()
+}
+
+/// Stems every word of a document as a pipeline stage, leaving
+/// punctuation and numbers unstemmed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stemmer;
+
+impl Stemmer {
+    pub fn new() -> Stemmer {
+        Stemmer
+    }
+}
+
+impl Stage for Stemmer {
+    fn kind(&self) -> StageKind {
+        StageKind::Stem
+    }
+
+    fn run(&self, document: &mut Document) -> Result<()> {
+        for index in 0..document.tokens().len() {
+            let token = &document.tokens()[index];
+            if !token.is_word() {
+                continue;
+            }
+            let stem = stem(token.text());
+            if let Some(annotation) = document.annotation_mut(index) {
+                annotation.set_stem(&stem);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A word part way through stemming. A `y` that acts as a consonant is
+/// held as `Y` until the end.
+struct Stemming {
+    chars: Vec<char>,
+    r1: usize,
+    r2: usize,
+}
+
+impl Stemming {
+    fn new(word: &str) -> Stemming {
+        let mut chars: Vec<char> = word.chars().collect();
+        for index in 0..chars.len() {
+            if chars[index] == 'y' && (index == 0 || is_vowel(chars[index - 1])) {
+                chars[index] = 'Y';
+            }
+        }
+        let r1 = PREFIXES
+            .iter()
+            .find(|prefix| word.starts_with(*prefix))
+            .map_or_else(|| region_after(&chars, 0), |prefix| prefix.len());
+        let r2 = region_after(&chars, r1);
+        Stemming { chars, r1, r2 }
+    }
+
+    fn ends_with(&self, suffix: &str) -> bool {
+        let suffix: Vec<char> = suffix.chars().collect();
+        self.chars.ends_with(&suffix)
+    }
+
+    /// Whether a suffix of `length` characters lies within the region
+    /// starting at `region`.
+    fn within(&self, length: usize, region: usize) -> bool {
+        self.chars.len() >= length && self.chars.len() - length >= region
+    }
+
+    fn replace(&mut self, length: usize, replacement: &str) {
+        self.chars.truncate(self.chars.len() - length);
+        self.chars.extend(replacement.chars());
+    }
+
+    fn has_vowel_before(&self, end: usize) -> bool {
+        self.chars[..end].iter().any(|&c| is_vowel(c))
+    }
+
+    /// Whether the word ends in a short syllable: a vowel between two
+    /// consonants, the last of them not `w`, `x` or `Y`, or a vowel then a
+    /// consonant at the start of the word.
+    fn ends_in_short_syllable(&self) -> bool {
+        match self.chars.as_slice() {
+            [first, second] => is_vowel(*first) && !is_vowel(*second),
+            [.., before, vowel, last] => {
+                !is_vowel(*before) && is_vowel(*vowel) && !is_vowel(*last) && !"wxY".contains(*last)
+            }
+            _ => false,
+        }
+    }
+
+    fn is_short(&self) -> bool {
+        self.r1 >= self.chars.len() && self.ends_in_short_syllable()
+    }
+
+    /// Removes the possessive `'s'`, `'s` and `'`.
+    fn step_0(&mut self) {
+        if let Some(suffix) = ["'s'", "'s", "'"]
+            .iter()
+            .find(|suffix| self.ends_with(suffix))
+        {
+            self.replace(suffix.len(), "");
+        }
+    }
+
+    /// Removes plural `-s` and `-es`.
+    fn step_1a(&mut self) {
+        let length = self.chars.len();
+        if self.ends_with("sses") {
+            self.replace(2, "");
+        } else if self.ends_with("ied") || self.ends_with("ies") {
+            let replacement = if length > 4 { "i" } else { "ie" };
+            self.replace(3, replacement);
+        } else if self.ends_with("s")
+            && !self.ends_with("us")
+            && !self.ends_with("ss")
+            && length > 2
+            && self.has_vowel_before(length - 2)
+        {
+            self.replace(1, "");
+        }
+    }
+
+    /// Removes `-ed`, `-ing` and their adverbs, then tidies up the end of
+    /// what is left.
+    fn step_1b(&mut self) {
+        let Some(suffix) = ["eedly", "ingly", "edly", "eed", "ing", "ed"]
+            .into_iter()
+            .find(|suffix| self.ends_with(suffix))
+        else {
+            return;
+        };
+        if suffix.starts_with("eed") {
+            if self.within(suffix.len(), self.r1) {
+                self.replace(suffix.len(), "ee");
+            }
+            return;
+        }
+        if !self.has_vowel_before(self.chars.len() - suffix.len()) {
+            return;
+        }
+        self.replace(suffix.len(), "");
+        if self.ends_with("at") || self.ends_with("bl") || self.ends_with("iz") {
+            self.chars.push('e');
+        } else if self.ends_with_double() {
+            self.chars.pop();
+        } else if self.is_short() {
+            self.chars.push('e');
+        }
+    }
+
+    /// Turns a final `y` after a consonant into `i`.
+    fn step_1c(&mut self) {
+        let length = self.chars.len();
+        if length > 2
+            && matches!(self.chars[length - 1], 'y' | 'Y')
+            && !is_vowel(self.chars[length - 2])
+        {
+            self.chars[length - 1] = 'i';
+        }
+    }
+
+    fn step_2(&mut self) {
+        let Some(&(suffix, replacement)) = STEP_2.iter().find(|(suffix, _)| self.ends_with(suffix))
+        else {
+            return;
+        };
+        if !self.within(suffix.len(), self.r1) {
+            return;
+        }
+        let before = self.chars.len() - suffix.len();
+        let allowed = match suffix {
+            "ogi" => before > 0 && self.chars[before - 1] == 'l',
+            "li" => before > 0 && "cdeghkmnrt".contains(self.chars[before - 1]),
+            _ => true,
+        };
+        if allowed {
+            self.replace(suffix.len(), replacement);
+        }
+    }
+
+    fn step_3(&mut self) {
+        let Some(&(suffix, replacement)) = STEP_3.iter().find(|(suffix, _)| self.ends_with(suffix))
+        else {
+            return;
+        };
+        let region = match suffix {
+            "ative" => self.r2,
+            _ => self.r1,
+        };
+        if self.within(suffix.len(), region) {
+            self.replace(suffix.len(), replacement);
+        }
+    }
+
+    fn step_4(&mut self) {
+        let Some(suffix) = STEP_4.iter().find(|suffix| self.ends_with(suffix)) else {
+            return;
+        };
+        if !self.within(suffix.len(), self.r2) {
+            return;
+        }
+        let before = self.chars.len() - suffix.len();
+        if *suffix != "ion" || (before > 0 && "st".contains(self.chars[before - 1])) {
+            self.replace(suffix.len(), "");
+        }
+    }
+
+    /// Removes a final `e`, and one `l` of a final `ll`.
+    fn step_5(&mut self) {
+        if self.ends_with("e") {
+            let in_r2 = self.within(1, self.r2);
+            let in_r1 = self.within(1, self.r1);
+            self.chars.pop();
+            let removable = in_r2 || (in_r1 && !self.ends_in_short_syllable());
+            if !removable {
+                self.chars.push('e');
+            }
+        } else if self.ends_with("ll") && self.within(1, self.r2) {
+            self.chars.pop();
+        }
+    }
+
+    fn ends_with_double(&self) -> bool {
+        ["bb", "dd", "ff", "gg", "mm", "nn", "pp", "rr", "tt"]
+            .iter()
+            .any(|double| self.ends_with(double))
+    }
+}
+
+impl std::fmt::Display for Stemming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let word: String = self
+            .chars
+            .iter()
+            .map(|&c| if c == 'Y' { 'y' } else { c })
+            .collect();
+        f.write_str(&word)
+    }
+}
+
+/// The start of the region after the first consonant that follows a
+/// vowel, looking from `start`.
+fn region_after(chars: &[char], start: usize) -> usize {
+    (start + 1..chars.len())
+        .find(|&index| !is_vowel(chars[index]) && is_vowel(chars[index - 1]))
+        .map_or(chars.len(), |index| index + 1)
+}
+
+fn is_vowel(c: char) -> bool {
+    "aeiouy".contains(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::Processor;
+    use crate::{Preprocessor, PreprocessorOptions};
+
+    /// Pairs from the Snowball English sample vocabulary.
+    const STEMS: [(&str, &str); 48] = [
+        ("consign", "consign"),
+        ("consigned", "consign"),
+        ("consignment", "consign"),
+        ("consistency", "consist"),
+        ("consistently", "consist"),
+        ("consolation", "consol"),
+        ("consolatory", "consolatori"),
+        ("consoled", "consol"),
+        ("consolingly", "consol"),
+        ("consolidate", "consolid"),
+        ("consonant", "conson"),
+        ("conspicuously", "conspicu"),
+        ("conspiracy", "conspiraci"),
+        ("conspirators", "conspir"),
+        ("constable", "constabl"),
+        ("constancy", "constanc"),
+        ("knackeries", "knackeri"),
+        ("knightly", "knight"),
+        ("generously", "generous"),
+        ("communication", "communic"),
+        ("happy", "happi"),
+        ("running", "run"),
+        ("hopping", "hop"),
+        ("hoping", "hope"),
+        ("caresses", "caress"),
+        ("ponies", "poni"),
+        ("ties", "tie"),
+        ("cats", "cat"),
+        ("agreed", "agre"),
+        ("feed", "feed"),
+        ("troubled", "troubl"),
+        ("sized", "size"),
+        ("filing", "file"),
+        ("relational", "relat"),
+        ("conditional", "condit"),
+        ("rational", "ration"),
+        ("digitizer", "digit"),
+        ("operator", "oper"),
+        ("effective", "effect"),
+        ("electricity", "electr"),
+        ("adjustment", "adjust"),
+        ("dependent", "depend"),
+        ("adoption", "adopt"),
+        ("irritant", "irrit"),
+        ("households", "household"),
+        ("skies", "sky"),
+        ("proceeds", "proceed"),
+        ("Romeo's", "romeo"),
+    ];
+
+    #[test]
+    fn stems_like_snowball() {
+        for (word, expected) in STEMS {
+            assert_eq!(stem(word), expected, "stem of {}", word);
+        }
+    }
+
+    #[test]
+    fn leaves_short_words_alone() {
+        assert_eq!(stem("is"), "is");
+        assert_eq!(stem("by"), "by");
+        assert_eq!(stem("'tis"), "tis");
+        assert_eq!(stem("say"), "say");
+        assert_eq!(stem("cry"), "cri");
+    }
+
+    #[test]
+    fn stems_the_words_of_a_document() {
+        let preprocessor =
+            Preprocessor::from_text("Consigned to 2 graves.", PreprocessorOptions::default())
+                .unwrap();
+        let mut processor = Processor::new();
+        processor.add_stage(Stemmer::new());
+        let document = processor.process(&preprocessor).unwrap();
+
+        let stems: Vec<&str> = document.words().filter_map(|word| word.stem()).collect();
+        assert_eq!(stems, ["consign", "to", "grave"]);
+        let stemmed = document
+            .annotated_tokens()
+            .filter(|token| token.stem().is_some());
+        assert_eq!(stemmed.count(), 3);
+    }
+}
@@ -0,0 +1,400 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, ErrorKind};
+use std::ops::Range;
+
+use crate::error::{Error, Result};
+use crate::sentences::SentenceStrategy;
+use crate::tokenizer::{Token, Tokenizer};
+use crate::vocabulary::Vocabulary;
+
+const SENTENCE_BATCH: usize = 512;
+
+/// Tokens a sentence may buffer before it is cut, so that text without
+/// terminators still streams.
+const MAX_SENTENCE_TOKENS: usize = 8 * SENTENCE_BATCH;
+
+/// Tokenizes a reader lazily, one buffer at a time.
+///
+/// Text is only tokenized up to the last whitespace seen so far, so no
+/// token is ever cut at a buffer boundary; memory use is bounded by the
+/// reader's buffer and the longest run of non-whitespace. Offsets, lines
+/// and columns are relative to the start of the stream. Every word yielded
+/// is also counted into the stream's vocabulary.
+pub struct TokenStream<R> {
+    reader: R,
+    tokenizer: Tokenizer,
+    vocabulary: Vocabulary,
+    undecoded: Vec<u8>,
+    pending: String,
+    ready: VecDeque<Token>,
+    offset: usize,
+    line: usize,
+    column: usize,
+    finished: bool,
+    failed: bool,
+}
+
+impl<R: BufRead> TokenStream<R> {
+    pub fn new(reader: R, tokenizer: Tokenizer) -> TokenStream<R> {
+        TokenStream::with_vocabulary(reader, tokenizer, Vocabulary::new())
+    }
+
+    /// A stream that counts into `vocabulary`, e.g. one created with
+    /// `Vocabulary::with_limit` to bound its size.
+    pub fn with_vocabulary(
+        reader: R,
+        tokenizer: Tokenizer,
+        mut vocabulary: Vocabulary,
+    ) -> TokenStream<R> {
+        vocabulary.begin_document();
+        TokenStream {
+            reader,
+            tokenizer,
+            vocabulary,
+            undecoded: Vec::new(),
+            pending: String::new(),
+            ready: VecDeque::new(),
+            offset: 0,
+            line: 1,
+            column: 1,
+            finished: false,
+            failed: false,
+        }
+    }
+
+    pub fn vocabulary(&self) -> &Vocabulary {
+        &self.vocabulary
+    }
+
+    pub fn into_vocabulary(self) -> Vocabulary {
+        self.vocabulary
+    }
+
+    fn fill(&mut self) -> Result<()> {
+        while self.ready.is_empty() && !self.finished {
+            let read = match self.reader.fill_buf() {
+                Ok(buffer) => {
+                    self.undecoded.extend_from_slice(buffer);
+                    buffer.len()
+                }
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error.into()),
+            };
+            self.reader.consume(read);
+            self.decode()?;
+
+            if read == 0 {
+                self.finished = true;
+                if !self.undecoded.is_empty() {
+                    return Err(Error::Encoding {
+                        offset: self.offset + self.pending.len(),
+                    });
+                }
+            }
+
+            let cut = match self.finished {
+                true => self.pending.len(),
+                false => cut_point(&self.pending),
+            };
+            self.tokenize_pending(cut)?;
+        }
+        Ok(())
+    }
+
+    /// Moves the valid UTF-8 prefix of the undecoded bytes into `pending`,
+    /// keeping back an incomplete character at the end.
+    fn decode(&mut self) -> Result<()> {
+        let valid = match std::str::from_utf8(&self.undecoded) {
+            Ok(text) => {
+                self.pending.push_str(text);
+                self.undecoded.len()
+            }
+            Err(error) => {
+                let valid = error.valid_up_to();
+                let text = std::str::from_utf8(&self.undecoded[..valid]).unwrap_or_default();
+                self.pending.push_str(text);
+                if error.error_len().is_some() {
+                    return Err(Error::Encoding {
+                        offset: self.offset + self.pending.len(),
+                    });
+                }
+                valid
+            }
+        };
+        self.undecoded.drain(..valid);
+        Ok(())
+    }
+
+    fn tokenize_pending(&mut self, cut: usize) -> Result<()> {
+        if cut == 0 {
+            return Ok(());
+        }
+        let chunk: String = self.pending.drain(..cut).collect();
+        let tokens = self
+            .tokenizer
+            .try_tokenize(&chunk)
+            .map_err(|error| match error {
+                Error::Tokenization { offset, message } => Error::Tokenization {
+                    offset: self.offset + offset,
+                    message,
+                },
+                error => error,
+            })?;
+
+        for mut token in tokens {
+            token.offset_by(self.offset, self.line, self.column);
+            self.vocabulary.add_token(&token);
+            self.ready.push_back(token);
+        }
+
+        self.offset += chunk.len();
+        for c in chunk.chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<R: BufRead> Iterator for TokenStream<R> {
+    type Item = Result<Token>;
+
+    fn next(&mut self) -> Option<Result<Token>> {
+        if self.failed {
+            return None;
+        }
+        if let Err(error) = self.fill() {
+            self.failed = true;
+            return Some(Err(error));
+        }
+        self.ready.pop_front().map(Ok)
+    }
+}
+
+/// Where `text` can safely be cut: the start of its last whitespace run,
+/// since the whitespace may continue and the word before it is complete.
+fn cut_point(text: &str) -> usize {
+    let last_whitespace = match text.rfind(char::is_whitespace) {
+        Some(index) => index,
+        None => return 0,
+    };
+    text[..last_whitespace]
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| !c.is_whitespace())
+        .map_or(0, |(index, c)| index + c.len_utf8())
+}
+
+/// One sentence read from a stream, with all its tokens including the
+/// whitespace between them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamedSentence {
+    span: Range<usize>,
+    tokens: Vec<Token>,
+}
+
+impl StreamedSentence {
+    /// Byte range of the sentence within the stream.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    pub fn text(&self) -> String {
+        self.tokens.iter().map(Token::text).collect()
+    }
+}
+
+/// Groups a `TokenStream` into sentences lazily.
+///
+/// Tokens are buffered only until the sentence they belong to is known to
+/// be complete, which is once the start of the following sentence has been
+/// seen. Only the incomplete last sentence stays buffered, and it is cut
+/// once it reaches `MAX_SENTENCE_TOKENS`, so memory stays bounded even for
+/// text without terminators.
+pub struct SentenceStream<R> {
+    tokens: TokenStream<R>,
+    strategy: SentenceStrategy,
+    buffer: VecDeque<Token>,
+    ready: VecDeque<StreamedSentence>,
+    finished: bool,
+    failed: bool,
+}
+
+impl<R: BufRead> SentenceStream<R> {
+    pub fn new(tokens: TokenStream<R>, strategy: SentenceStrategy) -> SentenceStream<R> {
+        SentenceStream {
+            tokens,
+            strategy,
+            buffer: VecDeque::new(),
+            ready: VecDeque::new(),
+            finished: false,
+            failed: false,
+        }
+    }
+
+    pub fn vocabulary(&self) -> &Vocabulary {
+        self.tokens.vocabulary()
+    }
+
+    pub fn into_vocabulary(self) -> Vocabulary {
+        self.tokens.into_vocabulary()
+    }
+
+    fn fill(&mut self) -> Result<()> {
+        while self.ready.is_empty() && !self.finished {
+            for _ in 0..SENTENCE_BATCH {
+                match self.tokens.next() {
+                    Some(token) => self.buffer.push_back(token?),
+                    None => {
+                        self.finished = true;
+                        break;
+                    }
+                }
+            }
+
+            let spans = self.strategy.split(&self.buffer);
+            let mut complete = match self.finished {
+                true => spans.len(),
+                false => spans.len().saturating_sub(1),
+            };
+            if complete == 0 && self.buffer.len() >= MAX_SENTENCE_TOKENS {
+                complete = spans.len();
+            }
+            for span in spans.into_iter().take(complete) {
+                self.take_sentence(span);
+            }
+        }
+        Ok(())
+    }
+
+    fn take_sentence(&mut self, span: Range<usize>) {
+        let mut tokens = Vec::new();
+        while let Some(token) = self.buffer.pop_front() {
+            if token.end() > span.end {
+                self.buffer.push_front(token);
+                break;
+            }
+            if token.start() >= span.start {
+                tokens.push(token);
+            }
+        }
+        self.ready.push_back(StreamedSentence { span, tokens });
+    }
+}
+
+impl<R: BufRead> Iterator for SentenceStream<R> {
+    type Item = Result<StreamedSentence>;
+
+    fn next(&mut self) -> Option<Result<StreamedSentence>> {
+        if self.failed {
+            return None;
+        }
+        if let Err(error) = self.fill() {
+            self.failed = true;
+            return Some(Err(error));
+        }
+        self.ready.pop_front().map(Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    const TEXT: &str = "SAMPSON  Gregory, on my word we\u{2019}ll not carry coals.\n\n\
+                        GREGORY  No, for then we should be colliers. Ay! \u{c9}t\u{e9}.\n";
+
+    fn reader(text: &str, capacity: usize) -> BufReader<&[u8]> {
+        BufReader::with_capacity(capacity, text.as_bytes())
+    }
+
+    #[test]
+    fn matches_the_in_memory_tokenizer() {
+        let expected: Vec<Token> = Tokenizer::default().tokenize(TEXT).into_iter().collect();
+        for capacity in [1, 2, 3, 7, 64] {
+            let streamed: Vec<Token> =
+                TokenStream::new(reader(TEXT, capacity), Tokenizer::default())
+                    .collect::<Result<_>>()
+                    .unwrap();
+            assert_eq!(streamed, expected, "capacity {}", capacity);
+        }
+    }
+
+    #[test]
+    fn matches_the_in_memory_sentences() {
+        let text = TEXT.repeat(40);
+        let tokens = Tokenizer::default().tokenize(&text);
+        let expected: Vec<Range<usize>> = SentenceStrategy::default()
+            .split(&tokens)
+            .into_iter()
+            .collect();
+
+        let stream = TokenStream::new(reader(&text, 5), Tokenizer::default());
+        let sentences: Vec<StreamedSentence> =
+            SentenceStream::new(stream, SentenceStrategy::default())
+                .collect::<Result<_>>()
+                .unwrap();
+        let spans: Vec<Range<usize>> = sentences.iter().map(StreamedSentence::span).collect();
+        assert_eq!(spans, expected);
+        for sentence in &sentences {
+            assert_eq!(sentence.text(), &text[sentence.span()]);
+        }
+    }
+
+    #[test]
+    fn cuts_sentences_without_terminators() {
+        let text = "and so on ".repeat(3000);
+        let stream = TokenStream::new(reader(&text, 64), Tokenizer::default());
+        let sentences: Vec<StreamedSentence> =
+            SentenceStream::new(stream, SentenceStrategy::default())
+                .collect::<Result<_>>()
+                .unwrap();
+        assert!(sentences.len() > 1);
+        for sentence in &sentences {
+            assert!(sentence.tokens().len() <= MAX_SENTENCE_TOKENS + SENTENCE_BATCH);
+            assert_eq!(sentence.text(), &text[sentence.span()]);
+        }
+        assert_eq!(sentences.last().unwrap().span().end, text.trim_end().len());
+    }
+
+    #[test]
+    fn counts_the_vocabulary_as_it_goes() {
+        let mut stream = TokenStream::new(reader(TEXT, 4), Tokenizer::default());
+        stream.by_ref().for_each(drop);
+        let vocabulary = stream.into_vocabulary();
+        assert_eq!(
+            vocabulary,
+            Vocabulary::from_tokens(&Tokenizer::default().tokenize(TEXT))
+        );
+    }
+
+    #[test]
+    fn reports_invalid_utf8_with_its_offset() {
+        let bytes: &[u8] = b"Romeo and \xffJuliet";
+        let results: Vec<Result<Token>> =
+            TokenStream::new(BufReader::with_capacity(3, bytes), Tokenizer::default()).collect();
+        assert!(matches!(
+            results.last(),
+            Some(Err(Error::Encoding { offset: 10 }))
+        ));
+    }
+
+    #[test]
+    fn reports_truncated_characters_at_the_end() {
+        let bytes: &[u8] = b"caf\xc3";
+        let results: Vec<Result<Token>> = TokenStream::new(bytes, Tokenizer::default()).collect();
+        assert!(matches!(
+            results.last(),
+            Some(Err(Error::Encoding { offset: 3 }))
+        ));
+    }
+}
@@ -0,0 +1,496 @@
+pub mod lexicon;
+pub mod perceptron;
+
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
+
+use crate::document::Document;
+use crate::error::{Error, Result};
+use crate::parser::part_of_speech::{PartOfSpeech, PartOfSpeechKind};
+use crate::parser::tagset::{PennTag, Tag, UniversalTag};
+use crate::processor::{Stage, StageKind};
+use crate::tokenizer::contractions::{is_apostrophe, ContractionTable};
+use crate::tokenizer::{Token, TokenKind};
+use lexicon::Lexicon;
+
+const POSSESSIVES: [&str; 9] = [
+    "my", "thy", "your", "our", "their", "his", "her", "its", "thine",
+];
+
+/// Words after which an ambiguous word is most likely a verb.
+const VERB_CONTEXT: [&str; 34] = [
+    "to", "i", "we", "you", "they", "he", "she", "thou", "ye", "will", "wilt", "shall", "shalt",
+    "would", "should", "can", "canst", "could", "may", "might", "must", "do", "does", "did",
+    "dost", "doth", "didst", "not", "let", "i'll", "we'll", "you'll", "they'll", "cannot",
+];
+
+const NOUN_SUFFIXES: [&str; 14] = [
+    "ness", "ment", "tion", "sion", "ity", "ship", "hood", "dom", "ism", "ist", "ance", "ence",
+    "ure", "age",
+];
+
+const ADJECTIVE_SUFFIXES: [&str; 12] = [
+    "ous", "ful", "less", "able", "ible", "ive", "ical", "ic", "ish", "al", "ant", "ary",
+];
+
+const VERB_SUFFIXES: [&str; 7] = ["ize", "ise", "ify", "eth", "ing", "ed", "'d"];
+
+/// Assigns a `PartOfSpeech` to every token but whitespace.
+///
+/// Words are looked up in a lexicon, through their expansion if they are
+/// contractions; when a word can take several classes the words around it
+/// pick among them, so `love` after `my` is a noun and after `I` a verb,
+/// and `to` before a verb is a particle. Unknown words are guessed from
+/// capitalisation and suffixes. Numbers are numerals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LexiconTagger {
+    lexicon: Lexicon,
+    contractions: ContractionTable,
+}
+
+impl LexiconTagger {
+    pub fn new() -> LexiconTagger {
+        LexiconTagger::with_lexicon(Lexicon::default())
+    }
+
+    pub fn with_lexicon(lexicon: Lexicon) -> LexiconTagger {
+        LexiconTagger {
+            lexicon,
+            contractions: ContractionTable::default(),
+        }
+    }
+
+    pub fn lexicon(&self) -> &Lexicon {
+        &self.lexicon
+    }
+
+    /// One entry per token; `None` for whitespace.
+    pub fn tag(&self, tokens: &VecDeque<Token>) -> Vec<Option<PartOfSpeech>> {
+        let mut tags = Vec::with_capacity(tokens.len());
+        let mut previous: Option<(String, PartOfSpeechKind)> = None;
+        let mut at_start = true;
+
+        for (index, token) in tokens.iter().enumerate() {
+            let kind = match token.kind() {
+                TokenKind::Word => {
+                    let next = tokens
+                        .iter()
+                        .skip(index + 1)
+                        .find(|token| !token.is_whitespace());
+                    let kind = self.tag_in_context(token.text(), previous.as_ref(), next, at_start);
+                    previous = Some((token.text().to_lowercase(), kind));
+                    at_start = false;
+                    Some(kind)
+                }
+                TokenKind::Number => {
+                    previous = Some((token.text().to_string(), PartOfSpeechKind::Numeral));
+                    at_start = false;
+                    Some(PartOfSpeechKind::Numeral)
+                }
+                TokenKind::Whitespace => {
+                    at_start |= token.text().contains('\n');
+                    None
+                }
+                TokenKind::Punctuation => {
+                    if matches!(token.text(), "." | "!" | "?" | ":" | ";" | "[") {
+                        at_start = true;
+                        previous = None;
+                    }
+                    Some(PartOfSpeechKind::Punctuation)
+                }
+            };
+            tags.push(kind.map(|kind| kind.with_word(token.text())));
+        }
+        tags
+    }
+
+    /// The most likely class of `word` on its own.
+    pub fn tag_word(&self, word: &str) -> PartOfSpeechKind {
+        self.candidates(word)
+            .first()
+            .copied()
+            .unwrap_or_else(|| guess(word, false, false))
+    }
+
+    /// The classes the lexicon allows for `word`, trying the expansion of a
+    /// contraction and the owner of a possessive if the word itself is not
+    /// listed.
+    fn candidates(&self, word: &str) -> Vec<PartOfSpeechKind> {
+        if let Some(kinds) = self.lexicon.get(word) {
+            return kinds.to_vec();
+        }
+        if let Some(expansion) = self.contractions.expand(word) {
+            let head = expansion.split_whitespace().next().unwrap_or_default();
+            if let Some(kinds) = self.lexicon.get(head) {
+                return kinds.to_vec();
+            }
+        }
+        if is_possessive(word) {
+            return vec![PartOfSpeechKind::Noun];
+        }
+        Vec::new()
+    }
+
+    fn tag_in_context(
+        &self,
+        word: &str,
+        previous: Option<&(String, PartOfSpeechKind)>,
+        next: Option<&Token>,
+        at_start: bool,
+    ) -> PartOfSpeechKind {
+        let candidates = self.candidates(word);
+        let after_verb_context =
+            previous.is_some_and(|(text, _)| VERB_CONTEXT.contains(&text.as_str()));
+
+        if candidates.is_empty() {
+            return guess(word, !at_start, after_verb_context);
+        }
+        if candidates.len() == 1 {
+            return candidates[0];
+        }
+
+        let lowercase = word.to_lowercase();
+        if lowercase == "an" && next.is_some_and(|next| !starts_with_vowel_sound(next.text())) {
+            return PartOfSpeechKind::Conjunction;
+        }
+        let followed_by_pause = next.is_some_and(|next| matches!(next.text(), "," | "!"));
+        if at_start && followed_by_pause && candidates.contains(&PartOfSpeechKind::Interjection) {
+            return PartOfSpeechKind::Interjection;
+        }
+
+        let next_kind = next
+            .filter(|next| next.is_word())
+            .map(|next| self.tag_word(next.text()));
+        let before_verb = matches!(
+            next_kind,
+            Some(PartOfSpeechKind::Verb | PartOfSpeechKind::Auxiliary)
+        );
+        if candidates.contains(&PartOfSpeechKind::Particle) {
+            return match before_verb || !candidates.contains(&PartOfSpeechKind::Preposition) {
+                true => PartOfSpeechKind::Particle,
+                false => PartOfSpeechKind::Preposition,
+            };
+        }
+        if candidates.contains(&PartOfSpeechKind::Auxiliary)
+            && candidates.contains(&PartOfSpeechKind::Verb)
+            && next_kind == Some(PartOfSpeechKind::Verb)
+        {
+            return PartOfSpeechKind::Auxiliary;
+        }
+        if candidates.contains(&PartOfSpeechKind::Determiner)
+            && matches!(
+                next_kind,
+                Some(PartOfSpeechKind::Noun | PartOfSpeechKind::Adjective)
+            )
+        {
+            return PartOfSpeechKind::Determiner;
+        }
+
+        let preferred: &[PartOfSpeechKind] = match previous {
+            Some((text, kind))
+                if matches!(
+                    kind,
+                    PartOfSpeechKind::Article
+                        | PartOfSpeechKind::Determiner
+                        | PartOfSpeechKind::Numeral
+                        | PartOfSpeechKind::Adjective
+                ) || POSSESSIVES.contains(&text.as_str()) =>
+            {
+                &[PartOfSpeechKind::Noun, PartOfSpeechKind::Adjective]
+            }
+            Some(_) if after_verb_context => &[PartOfSpeechKind::Verb],
+            _ => &[],
+        };
+        preferred
+            .iter()
+            .find(|kind| candidates.contains(kind))
+            .copied()
+            .unwrap_or(candidates[0])
+    }
+}
+
+impl Default for LexiconTagger {
+    fn default() -> LexiconTagger {
+        LexiconTagger::new()
+    }
+}
+
+impl Stage for LexiconTagger {
+    fn kind(&self) -> StageKind {
+        StageKind::Tag
+    }
+
+    fn run(&self, document: &mut Document) -> Result<()> {
+        let tags = self.tag(document.tokens());
+        for (index, tag) in tags.into_iter().enumerate() {
+            if let (Some(tag), Some(annotation)) = (tag, document.annotation_mut(index)) {
+                annotation.set_part_of_speech(tag);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A sentence of a tagged corpus: each word with its class, or `None` for
+/// untagged tokens and those outside our classes.
+pub type TaggedSentence = Vec<(String, Option<PartOfSpeechKind>)>;
+
+pub fn load_tagged(path: &Path) -> Result<Vec<TaggedSentence>> {
+    parse_tagged(&fs::read_to_string(path)?)
+}
+
+/// Reads a tagged corpus with one sentence per line, written as
+/// space-separated `word/LABEL` pairs. Labels can be our own, Universal
+/// Dependencies or Penn Treebank tags, in that order of precedence; `X`,
+/// `FW` and the like, and `_`, mark untagged tokens. Standard tags are
+/// narrowed down by their word, as `Tag::refine` does. Blank lines and `#`
+/// comments are ignored.
+pub fn parse_tagged(text: &str) -> Result<Vec<TaggedSentence>> {
+    let mut sentences = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let sentence = line
+            .split_whitespace()
+            .map(|pair| {
+                let (word, label) = pair.rsplit_once('/').filter(|(word, _)| !word.is_empty())?;
+                let kind = match label {
+                    "_" => None,
+                    label => match PartOfSpeechKind::from_label(label) {
+                        Some(kind) => Some(kind),
+                        None => UniversalTag::from_label(label)
+                            .map(Tag::from)
+                            .or_else(|| PennTag::from_label(label).map(Tag::from))?
+                            .refine(word)
+                            .kind(),
+                    },
+                };
+                Some((word.to_string(), kind))
+            })
+            .collect::<Option<TaggedSentence>>()
+            .ok_or_else(|| Error::Parse {
+                line: number + 1,
+                message: format!("invalid tagged sentence {:?}", line),
+            })?;
+        sentences.push(sentence);
+    }
+    Ok(sentences)
+}
+
+/// Guesses the class of a word that is not in the lexicon.
+fn guess(word: &str, capitalized_mid_sentence: bool, after_verb_context: bool) -> PartOfSpeechKind {
+    let lowercase = word.to_lowercase();
+    let starts_upper = word.chars().next().is_some_and(char::is_uppercase);
+    if starts_upper && (capitalized_mid_sentence || !word.chars().any(char::is_lowercase)) {
+        return PartOfSpeechKind::Noun;
+    }
+    if let Some((_, last)) = lowercase.rsplit_once('-') {
+        return match last.ends_with("ed") {
+            true => PartOfSpeechKind::Adjective,
+            false => PartOfSpeechKind::Noun,
+        };
+    }
+    if lowercase.ends_with("'st") || lowercase.ends_with("'d") || lowercase.ends_with("'n") {
+        return PartOfSpeechKind::Verb;
+    }
+    if after_verb_context {
+        return PartOfSpeechKind::Verb;
+    }
+
+    let has_suffix = |suffixes: &[&str]| {
+        suffixes
+            .iter()
+            .any(|suffix| lowercase.len() > suffix.len() + 2 && lowercase.ends_with(suffix))
+    };
+    if lowercase.len() > 4 && lowercase.ends_with("ly") {
+        PartOfSpeechKind::Adverb
+    } else if has_suffix(&NOUN_SUFFIXES) {
+        PartOfSpeechKind::Noun
+    } else if has_suffix(&VERB_SUFFIXES) {
+        PartOfSpeechKind::Verb
+    } else if has_suffix(&ADJECTIVE_SUFFIXES) || has_suffix(&["est"]) {
+        PartOfSpeechKind::Adjective
+    } else {
+        PartOfSpeechKind::Noun
+    }
+}
+
+fn is_possessive(word: &str) -> bool {
+    let mut chars = word.chars().rev();
+    match (chars.next(), chars.next()) {
+        (Some(last), Some(before)) if is_apostrophe(last) => before == 's' || before == 'S',
+        (Some('s' | 'S'), Some(before)) => is_apostrophe(before),
+        _ => false,
+    }
+}
+
+fn starts_with_vowel_sound(word: &str) -> bool {
+    word.chars()
+        .next()
+        .is_some_and(|c| "aeiouhAEIOUH".contains(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+
+    pub(crate) const TAGGED: &str = include_str!("tagger/romeo_tagged.txt");
+    pub(crate) const CORPUS: &str = include_str!("../romeo_and_juliet.txt");
+    const HELD_OUT: &str = include_str!("tagger/romeo_held_out.txt");
+
+    fn tag(text: &str) -> Vec<(String, &'static str)> {
+        let tokens = Tokenizer::default().tokenize(text);
+        LexiconTagger::new()
+            .tag(&tokens)
+            .into_iter()
+            .zip(&tokens)
+            .filter(|(_, token)| token.kind() != TokenKind::Punctuation)
+            .filter_map(|(tag, token)| Some((token.text().to_string(), tag?.label())))
+            .collect()
+    }
+
+    #[test]
+    fn disambiguates_by_the_previous_word() {
+        let tags = tag("I love my love. Thou art moved to strike.");
+        let labels: Vec<&str> = tags.iter().map(|(_, label)| *label).collect();
+        assert_eq!(
+            labels,
+            ["PRON", "VERB", "PRON", "NOUN", "PRON", "AUX", "VERB", "PART", "VERB"]
+        );
+    }
+
+    #[test]
+    fn fills_in_the_features_of_tagged_words() {
+        use crate::parser::part_of_speech::{
+            Adjective, Adverb, Conjunction, Interjection, Preposition,
+        };
+        use crate::parser::word_enums::{
+            ConjunctionCategory, ConjunctionType, Degree, Modifies, PrepositionCase, Sentiment,
+        };
+
+        let tokens = Tokenizer::default()
+            .tokenize("Alas, I weep because the fairest of maids is very cold.");
+        let tags: Vec<PartOfSpeech> = LexiconTagger::new()
+            .tag(&tokens)
+            .into_iter()
+            .flatten()
+            .collect();
+        let find = |word: &str| {
+            tags.iter()
+                .find(|tag| tag.as_word().to_string() == word)
+                .unwrap()
+        };
+        assert_eq!(find("Alas").sentiment(), Sentiment::Pain);
+        let because = find("because");
+        assert_eq!(because.conjunction_type(), ConjunctionType::Subordinating);
+        assert_eq!(because.category(), ConjunctionCategory::Causal);
+        assert_eq!(find("fairest").adjective_degree(), Degree::Superlative);
+        assert_eq!(find("of").case(), PrepositionCase::Genitive);
+        assert_eq!(find("very").modifies(), Modifies::Adjective);
+    }
+
+    #[test]
+    fn guesses_unknown_words() {
+        let tagger = LexiconTagger::new();
+        assert_eq!(tagger.tag_word("Capulet's"), PartOfSpeechKind::Noun);
+        assert_eq!(tagger.tag_word("furiously"), PartOfSpeechKind::Adverb);
+        assert_eq!(tagger.tag_word("forgiveness"), PartOfSpeechKind::Noun);
+        assert_eq!(tagger.tag_word("speaketh"), PartOfSpeechKind::Verb);
+        assert_eq!(tagger.tag_word("star-crossed"), PartOfSpeechKind::Adjective);
+        assert_eq!(tagger.tag_word("we'll"), PartOfSpeechKind::Pronoun);
+        assert_eq!(tag("SAMPSON  Ay, Verona.")[2].1, "NOUN");
+    }
+
+    /// The share of the words on the `lines` of the corpus, counted from
+    /// zero, that are tagged as in `tagged`.
+    fn accuracy(lines: impl Fn(&usize) -> bool, tagged: &str) -> f64 {
+        let text: String = CORPUS
+            .lines()
+            .enumerate()
+            .filter(|(number, _)| lines(number))
+            .map(|(_, line)| format!("{}\n", line))
+            .collect();
+        let expected: Vec<(String, &str)> = parse_tagged(tagged)
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .map(|(word, kind)| (word, kind.unwrap().label()))
+            .collect();
+        let actual = tag(&text);
+        assert_eq!(actual.len(), expected.len());
+
+        let mut correct = 0;
+        for ((word, label), (expected_word, expected_label)) in actual.iter().zip(&expected) {
+            assert_eq!(word, expected_word);
+            if label == expected_label {
+                correct += 1;
+            }
+        }
+        correct as f64 / expected.len() as f64
+    }
+
+    #[test]
+    fn tags_the_hand_tagged_slice() {
+        // The lexicon holds the rare words of this slice, so this guards
+        // against regressions; the held-out passage measures accuracy.
+        let accuracy = accuracy(
+            |number| (9..23).contains(number) || (33..73).contains(number),
+            TAGGED,
+        );
+        assert!(accuracy >= 0.95, "accuracy {:.3}", accuracy);
+    }
+
+    #[test]
+    fn tags_a_held_out_passage() {
+        let accuracy = accuracy(|number| (1340..1365).contains(number), HELD_OUT);
+        assert!(accuracy >= 0.85, "accuracy {:.3}", accuracy);
+    }
+
+    #[test]
+    fn reads_tagged_corpora() {
+        let sentences = parse_tagged("# comment\nO/INTJ Romeo/NOUN !/PUNCT\n\n").unwrap();
+        assert_eq!(
+            sentences,
+            [vec![
+                ("O".to_string(), Some(PartOfSpeechKind::Interjection)),
+                ("Romeo".to_string(), Some(PartOfSpeechKind::Noun)),
+                ("!".to_string(), Some(PartOfSpeechKind::Punctuation)),
+            ]]
+        );
+        let kinds: Vec<Option<PartOfSpeechKind>> =
+            parse_tagged("Romeo/NNP wilt/MD thou/PRP ?/. amor/FW\n")
+                .unwrap()
+                .remove(0)
+                .into_iter()
+                .map(|(_, kind)| kind)
+                .collect();
+        assert_eq!(
+            kinds,
+            [
+                Some(PartOfSpeechKind::Noun),
+                Some(PartOfSpeechKind::Modal),
+                Some(PartOfSpeechKind::Pronoun),
+                Some(PartOfSpeechKind::Punctuation),
+                None,
+            ]
+        );
+        assert!(matches!(
+            parse_tagged("Romeo/NOUN\nJuliet\n"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn parses_lexicon_files() {
+        let lexicon = Lexicon::parse("# test\nwherefore\tADV,CONJ\n").unwrap();
+        assert_eq!(
+            lexicon.get("Wherefore"),
+            Some(&[PartOfSpeechKind::Adverb, PartOfSpeechKind::Conjunction][..])
+        );
+        assert!(Lexicon::parse("wherefore\tWHY\n").is_err());
+        assert!(Lexicon::default().len() > 1000);
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::parser::part_of_speech::PartOfSpeechKind;
+use crate::vocabulary::normalize;
+
+const BUNDLED: &str = include_str!("lexicon.tsv");
+
+/// Words with the parts of speech they can take, most likely first.
+///
+/// `Lexicon::new` is empty; `Lexicon::default` holds the bundled English
+/// lexicon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lexicon {
+    entries: HashMap<String, Vec<PartOfSpeechKind>>,
+}
+
+impl Lexicon {
+    pub fn new() -> Lexicon {
+        Lexicon {
+            entries: HashMap::new(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Lexicon> {
+        Lexicon::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Reads one entry per line: the word, a tab and a comma-separated list
+    /// of labels such as `NOUN,VERB`. Blank lines and `#` comments are
+    /// ignored.
+    pub fn parse(text: &str) -> Result<Lexicon> {
+        let mut lexicon = Lexicon::new();
+        for (number, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (word, labels) = line
+                .split_once('\t')
+                .ok_or_else(|| invalid_line(number, line))?;
+            let kinds = labels
+                .split(',')
+                .map(|label| PartOfSpeechKind::from_label(label.trim()))
+                .collect::<Option<Vec<PartOfSpeechKind>>>()
+                .ok_or_else(|| invalid_line(number, line))?;
+            lexicon.insert(word, kinds);
+        }
+        Ok(lexicon)
+    }
+
+    /// Adds the classes of `word` after any it already has.
+    pub fn insert(&mut self, word: &str, kinds: Vec<PartOfSpeechKind>) {
+        let key = normalize(word).unwrap_or_else(|| word.to_lowercase());
+        let entry = self.entries.entry(key).or_default();
+        for kind in kinds {
+            if !entry.contains(&kind) {
+                entry.push(kind);
+            }
+        }
+    }
+
+    /// The classes of `word`, ignoring case and the shape of apostrophes.
+    pub fn get(&self, word: &str) -> Option<&[PartOfSpeechKind]> {
+        let key = normalize(word).unwrap_or_else(|| word.to_lowercase());
+        self.entries.get(&key).map(Vec::as_slice)
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.get(word).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Default for Lexicon {
+    fn default() -> Lexicon {
+        Lexicon::parse(BUNDLED).expect("the bundled lexicon is well formed")
+    }
+}
+
+fn invalid_line(number: usize, line: &str) -> Error {
+    Error::Parse {
+        line: number + 1,
+        message: format!("invalid lexicon entry {:?}", line),
+    }
+}
@@ -0,0 +1,610 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+
+use crate::document::Document;
+use crate::error::{Error, Result};
+use crate::parser::part_of_speech::{PartOfSpeech, PartOfSpeechKind};
+use crate::processor::{Stage, StageKind};
+use crate::tokenizer::{Token, TokenKind};
+
+use super::TaggedSentence;
+
+const START: [&str; 2] = ["-START-", "-START2-"];
+const END: [&str; 2] = ["-END-", "-END2-"];
+const UNTAGGED: &str = "-NONE-";
+
+/// Words seen at least this often with one class at least this share of
+/// the time are tagged from a dictionary instead of the model.
+const TAG_DICTIONARY_FREQUENCY: u64 = 20;
+const TAG_DICTIONARY_RATIO: f64 = 0.97;
+
+/// A part of speech with the tagger's confidence in it, from 0 to 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Prediction {
+    part_of_speech: PartOfSpeech,
+    confidence: f64,
+}
+
+impl Prediction {
+    pub fn part_of_speech(&self) -> &PartOfSpeech {
+        &self.part_of_speech
+    }
+
+    pub fn into_part_of_speech(self) -> PartOfSpeech {
+        self.part_of_speech
+    }
+
+    pub fn confidence(&self) -> f64 {
+        self.confidence
+    }
+}
+
+/// An averaged perceptron part-of-speech tagger, after Collins (2002) and
+/// Honnibal's feature set.
+///
+/// Each word is scored from its own spelling, its neighbours and the two
+/// classes predicted before it. Frequent words that nearly always take one
+/// class skip the model. Confidence is the softmax of the class scores.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PerceptronTagger {
+    weights: HashMap<String, HashMap<PartOfSpeechKind, f64>>,
+    tag_dictionary: HashMap<String, PartOfSpeechKind>,
+}
+
+impl PerceptronTagger {
+    /// A tagger with no model, which tags every word as a noun.
+    pub fn new() -> PerceptronTagger {
+        PerceptronTagger::default()
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        Ok(fs::write(path, self.to_string())?)
+    }
+
+    pub fn load(path: &Path) -> Result<PerceptronTagger> {
+        PerceptronTagger::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Reads a model in the format written by `Display`: one tab-separated
+    /// `weight` or `tag` entry per line, blank lines and `#` comments
+    /// ignored.
+    pub fn parse(text: &str) -> Result<PerceptronTagger> {
+        let mut tagger = PerceptronTagger::new();
+        for (number, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split('\t').collect();
+            match fields.as_slice() {
+                ["weight", feature, label, weight] => {
+                    let kind = kind(label).ok_or_else(|| invalid_line(number, line))?;
+                    let weight = weight.parse().map_err(|_| invalid_line(number, line))?;
+                    tagger
+                        .weights
+                        .entry(feature.to_string())
+                        .or_default()
+                        .insert(kind, weight);
+                }
+                ["tag", word, label] => {
+                    let kind = kind(label).ok_or_else(|| invalid_line(number, line))?;
+                    tagger.tag_dictionary.insert(word.to_string(), kind);
+                }
+                _ => return Err(invalid_line(number, line)),
+            }
+        }
+        Ok(tagger)
+    }
+
+    /// One prediction per token; `None` for whitespace. The classes before
+    /// a word are those of the current sentence, which restarts after `.`,
+    /// `!`, `?` and `;`.
+    pub fn tag(&self, tokens: &VecDeque<Token>) -> Vec<Option<Prediction>> {
+        let mut predictions: Vec<Option<Prediction>> = vec![None; tokens.len()];
+        let mut sentence: Vec<(usize, &str)> = Vec::new();
+
+        for (index, token) in tokens.iter().enumerate() {
+            if token.kind() == TokenKind::Whitespace {
+                continue;
+            }
+            sentence.push((index, token.text()));
+            let ends_sentence = matches!(token.text(), "." | "!" | "?" | ";");
+            if ends_sentence {
+                self.tag_sentence(&sentence, &mut predictions);
+                sentence.clear();
+            }
+        }
+        self.tag_sentence(&sentence, &mut predictions);
+        predictions
+    }
+
+    fn tag_sentence(&self, sentence: &[(usize, &str)], predictions: &mut [Option<Prediction>]) {
+        let words: Vec<&str> = sentence.iter().map(|&(_, word)| word).collect();
+        let tagged = self.tag_words(&words);
+        for (&(index, word), (kind, confidence)) in sentence.iter().zip(tagged) {
+            predictions[index] = Some(Prediction {
+                part_of_speech: kind.with_word(word),
+                confidence,
+            });
+        }
+    }
+
+    /// Tags one sentence of words. Punctuation, with no letters or digits,
+    /// is tagged as such without consulting the model.
+    pub fn tag_words(&self, words: &[&str]) -> Vec<(PartOfSpeechKind, f64)> {
+        let context = context(words);
+        let mut previous = START[0].to_string();
+        let mut before_previous = START[1].to_string();
+        let mut tags = Vec::with_capacity(words.len());
+
+        for (index, word) in words.iter().enumerate() {
+            let tag = match self.tag_dictionary.get(&normalize(word)) {
+                _ if is_punctuation(word) => (PartOfSpeechKind::Punctuation, 1.0),
+                Some(&kind) => (kind, 1.0),
+                None => {
+                    let features = features(index, word, &context, &previous, &before_previous);
+                    self.predict(&features)
+                }
+            };
+            before_previous = previous;
+            previous = tag.0.label().to_string();
+            tags.push(tag);
+        }
+        tags
+    }
+
+    /// The share of tagged words in `sentences` whose class is predicted
+    /// correctly.
+    pub fn accuracy(&self, sentences: &[TaggedSentence]) -> f64 {
+        let (mut correct, mut total) = (0, 0);
+        for sentence in sentences {
+            let words: Vec<&str> = sentence.iter().map(|(word, _)| word.as_str()).collect();
+            let predicted = self.tag_words(&words);
+            for ((_, expected), (kind, _)) in sentence.iter().zip(predicted) {
+                if let Some(expected) = expected {
+                    total += 1;
+                    if kind == *expected {
+                        correct += 1;
+                    }
+                }
+            }
+        }
+        match total {
+            0 => 0.0,
+            total => correct as f64 / total as f64,
+        }
+    }
+
+    fn scores(&self, features: &[String]) -> HashMap<PartOfSpeechKind, f64> {
+        let mut scores = HashMap::new();
+        for feature in features {
+            if let Some(weights) = self.weights.get(feature) {
+                for (&kind, &weight) in weights {
+                    *scores.entry(kind).or_insert(0.0) += weight;
+                }
+            }
+        }
+        scores
+    }
+
+    /// The best-scoring class and its softmax probability. Ties go to the
+    /// class listed first in `PartOfSpeechKind::ALL`.
+    fn predict(&self, features: &[String]) -> (PartOfSpeechKind, f64) {
+        let scores = self.scores(features);
+        let best = PartOfSpeechKind::ALL
+            .into_iter()
+            .filter(|kind| scores.contains_key(kind))
+            .fold(None, |best: Option<(PartOfSpeechKind, f64)>, kind| {
+                let score = scores[&kind];
+                match best {
+                    Some((_, best_score)) if best_score >= score => best,
+                    _ => Some((kind, score)),
+                }
+            });
+        let (kind, best_score) = match best {
+            Some(best) => best,
+            None => return (PartOfSpeechKind::Noun, 0.0),
+        };
+        let total: f64 = scores
+            .values()
+            .map(|score| (score - best_score).exp())
+            .sum();
+        (kind, 1.0 / total)
+    }
+}
+
+/// Writes the model sorted, so that equal models give equal files.
+impl Display for PerceptronTagger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# averaged perceptron part-of-speech model")?;
+        let mut tags: Vec<(&String, &str)> = self
+            .tag_dictionary
+            .iter()
+            .map(|(word, kind)| (word, kind.label()))
+            .collect();
+        tags.sort();
+        for (word, label) in tags {
+            writeln!(f, "tag\t{}\t{}", word, label)?;
+        }
+        let mut weights: Vec<(&String, &str, f64)> = self
+            .weights
+            .iter()
+            .flat_map(|(feature, weights)| {
+                weights
+                    .iter()
+                    .map(move |(kind, &weight)| (feature, kind.label(), weight))
+            })
+            .collect();
+        weights.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+        for (feature, label, weight) in weights {
+            writeln!(f, "weight\t{}\t{}\t{}", feature, label, weight)?;
+        }
+        Ok(())
+    }
+}
+
+impl Stage for PerceptronTagger {
+    fn kind(&self) -> StageKind {
+        StageKind::Tag
+    }
+
+    fn run(&self, document: &mut Document) -> Result<()> {
+        let predictions = self.tag(document.tokens());
+        for (index, prediction) in predictions.into_iter().enumerate() {
+            if let (Some(prediction), Some(annotation)) =
+                (prediction, document.annotation_mut(index))
+            {
+                annotation.set_confidence(prediction.confidence);
+                annotation.set_part_of_speech(prediction.part_of_speech);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Trains a `PerceptronTagger` from tagged sentences.
+///
+/// Sentences can be added in several batches; `finish` then makes the
+/// given number of passes over all of them, in a shuffled but
+/// reproducible order, and averages the weights.
+#[derive(Debug, Clone)]
+pub struct PerceptronTrainer {
+    sentences: Vec<TaggedSentence>,
+    iterations: usize,
+}
+
+impl PerceptronTrainer {
+    pub fn new(iterations: usize) -> PerceptronTrainer {
+        PerceptronTrainer {
+            sentences: Vec::new(),
+            iterations,
+        }
+    }
+
+    pub fn train(&mut self, sentences: &[TaggedSentence]) {
+        self.sentences.extend_from_slice(sentences);
+    }
+
+    pub fn finish(self) -> PerceptronTagger {
+        let mut model = Training {
+            tagger: PerceptronTagger {
+                weights: HashMap::new(),
+                tag_dictionary: tag_dictionary(&self.sentences),
+            },
+            totals: HashMap::new(),
+            timestamps: HashMap::new(),
+            instances: 0,
+        };
+
+        let mut order: Vec<usize> = (0..self.sentences.len()).collect();
+        let mut random = 0x2545_f491_4f6c_dd1d_u64;
+        for _ in 0..self.iterations {
+            for sentence in &order {
+                model.learn(&self.sentences[*sentence]);
+            }
+            for i in (1..order.len()).rev() {
+                random ^= random << 13;
+                random ^= random >> 7;
+                random ^= random << 17;
+                order.swap(i, (random % (i as u64 + 1)) as usize);
+            }
+        }
+        model.average()
+    }
+}
+
+/// A tagger being trained, with the running totals needed to average its
+/// weights over every update.
+struct Training {
+    tagger: PerceptronTagger,
+    totals: HashMap<(String, PartOfSpeechKind), f64>,
+    timestamps: HashMap<(String, PartOfSpeechKind), u64>,
+    instances: u64,
+}
+
+impl Training {
+    fn learn(&mut self, sentence: &TaggedSentence) {
+        let words: Vec<&str> = sentence.iter().map(|(word, _)| word.as_str()).collect();
+        let context = context(&words);
+        let mut previous = START[0].to_string();
+        let mut before_previous = START[1].to_string();
+
+        for (index, (word, truth)) in sentence.iter().enumerate() {
+            if is_punctuation(word) {
+                before_previous = previous;
+                previous = PartOfSpeechKind::Punctuation.label().to_string();
+            } else if let Some(truth) = *truth {
+                let guess = match self.tagger.tag_dictionary.get(&normalize(word)) {
+                    Some(&kind) => kind,
+                    None => {
+                        let features = features(index, word, &context, &previous, &before_previous);
+                        let (guess, _) = self.tagger.predict(&features);
+                        self.update(truth, guess, &features);
+                        guess
+                    }
+                };
+                before_previous = previous;
+                previous = guess.label().to_string();
+            } else {
+                before_previous = previous;
+                previous = UNTAGGED.to_string();
+            }
+        }
+    }
+
+    fn update(&mut self, truth: PartOfSpeechKind, guess: PartOfSpeechKind, features: &[String]) {
+        self.instances += 1;
+        if truth == guess {
+            return;
+        }
+        for feature in features {
+            self.adjust(feature, truth, 1.0);
+            self.adjust(feature, guess, -1.0);
+        }
+    }
+
+    fn adjust(&mut self, feature: &str, kind: PartOfSpeechKind, change: f64) {
+        let key = (feature.to_string(), kind);
+        let weight = self
+            .tagger
+            .weights
+            .get(feature)
+            .and_then(|weights| weights.get(&kind))
+            .copied()
+            .unwrap_or(0.0);
+        let since = self.instances - self.timestamps.get(&key).copied().unwrap_or(0);
+        *self.totals.entry(key.clone()).or_insert(0.0) += since as f64 * weight;
+        self.timestamps.insert(key, self.instances);
+        self.tagger
+            .weights
+            .entry(feature.to_string())
+            .or_default()
+            .insert(kind, weight + change);
+    }
+
+    fn average(mut self) -> PerceptronTagger {
+        let instances = self.instances.max(1) as f64;
+        for (feature, weights) in self.tagger.weights.iter_mut() {
+            for (kind, weight) in weights.iter_mut() {
+                let key = (feature.clone(), *kind);
+                let since = self.instances - self.timestamps.get(&key).copied().unwrap_or(0);
+                let total = self.totals.get(&key).copied().unwrap_or(0.0) + since as f64 * *weight;
+                *weight = (total / instances * 1000.0).round() / 1000.0;
+            }
+            weights.retain(|_, weight| *weight != 0.0);
+        }
+        self.tagger.weights.retain(|_, weights| !weights.is_empty());
+        self.tagger
+    }
+}
+
+/// Words that are frequent and nearly unambiguous in the training data.
+fn tag_dictionary(sentences: &[TaggedSentence]) -> HashMap<String, PartOfSpeechKind> {
+    let mut counts: HashMap<String, HashMap<PartOfSpeechKind, u64>> = HashMap::new();
+    for (word, kind) in sentences.iter().flatten() {
+        if let Some(kind) = kind {
+            *counts
+                .entry(normalize(word))
+                .or_default()
+                .entry(*kind)
+                .or_insert(0) += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .filter_map(|(word, kinds)| {
+            let total: u64 = kinds.values().sum();
+            let (&kind, &count) = kinds
+                .iter()
+                .max_by_key(|&(kind, &count)| (count, Reverse(*kind as u8)))?;
+            let frequent = total >= TAG_DICTIONARY_FREQUENCY;
+            (frequent && count as f64 / total as f64 >= TAG_DICTIONARY_RATIO)
+                .then_some((word, kind))
+        })
+        .collect()
+}
+
+/// The normalized words of a sentence padded with start and end markers.
+fn context(words: &[&str]) -> Vec<String> {
+    START
+        .iter()
+        .map(|marker| marker.to_string())
+        .chain(words.iter().map(|word| normalize(word)))
+        .chain(END.iter().map(|marker| marker.to_string()))
+        .collect()
+}
+
+fn features(
+    index: usize,
+    word: &str,
+    context: &[String],
+    previous: &str,
+    before_previous: &str,
+) -> Vec<String> {
+    let i = index + 2;
+    let normalized = &context[i];
+    vec![
+        "bias".to_string(),
+        format!("i suffix {}", suffix(normalized)),
+        format!("i pref1 {}", normalized.chars().next().unwrap_or_default()),
+        format!("i shape {}", shape(word)),
+        format!("i-1 tag {}", previous),
+        format!("i-2 tag {}", before_previous),
+        format!("i tag+i-2 tag {} {}", previous, before_previous),
+        format!("i word {}", normalized),
+        format!("i-1 tag+i word {} {}", previous, normalized),
+        format!("i-1 word {}", context[i - 1]),
+        format!("i-1 suffix {}", suffix(&context[i - 1])),
+        format!("i-2 word {}", context[i - 2]),
+        format!("i+1 word {}", context[i + 1]),
+        format!("i+1 suffix {}", suffix(&context[i + 1])),
+        format!("i+2 word {}", context[i + 2]),
+    ]
+}
+
+/// Lowercases a word and collapses numbers and hyphenated compounds into
+/// placeholders.
+fn normalize(word: &str) -> String {
+    let is_number = word.chars().next().is_some_and(|c| c.is_ascii_digit())
+        && word
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == ',' || c == '.');
+    if is_number && word.len() == 4 {
+        "!YEAR".to_string()
+    } else if is_number {
+        "!DIGITS".to_string()
+    } else if word.contains('-') && !word.starts_with('-') {
+        "!HYPHEN".to_string()
+    } else {
+        word.to_lowercase().replace('\u{2019}', "'")
+    }
+}
+
+fn is_punctuation(word: &str) -> bool {
+    !word.chars().any(char::is_alphanumeric)
+}
+
+fn suffix(word: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    chars[chars.len().saturating_sub(3)..].iter().collect()
+}
+
+/// `X` for an all-capital word, `Xx` for a capitalized one, `x` otherwise.
+fn shape(word: &str) -> &'static str {
+    let mut letters = word.chars().filter(|c| c.is_alphabetic());
+    match letters.next() {
+        Some(first) if first.is_uppercase() => match letters.any(char::is_lowercase) {
+            true => "Xx",
+            false => "X",
+        },
+        _ => "x",
+    }
+}
+
+fn kind(label: &str) -> Option<PartOfSpeechKind> {
+    PartOfSpeechKind::from_label(label)
+}
+
+fn invalid_line(number: usize, line: &str) -> Error {
+    Error::Parse {
+        line: number + 1,
+        message: format!("invalid perceptron model entry {:?}", line),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::preprocessor::{Preprocessor, PreprocessorOptions};
+    use crate::processor::Processor;
+    use crate::tagger::parse_tagged;
+    use crate::tagger::tests::TAGGED;
+
+    const MOVES: &str = "\
+I/PRON move/VERB ./PUNCT
+We/PRON move/VERB the/DET table/NOUN ./PUNCT
+A/DET move/NOUN ./PUNCT
+The/DET move/NOUN was/VERB bold/ADJ ./PUNCT
+They/PRON love/VERB the/DET move/NOUN ./PUNCT
+Thou/PRON dost/VERB move/VERB me/PRON ./PUNCT
+";
+
+    fn trained(corpus: &str, iterations: usize) -> PerceptronTagger {
+        let mut trainer = PerceptronTrainer::new(iterations);
+        trainer.train(&parse_tagged(corpus).unwrap());
+        trainer.finish()
+    }
+
+    fn labels(tagger: &PerceptronTagger, words: &[&str]) -> Vec<&'static str> {
+        tagger
+            .tag_words(words)
+            .into_iter()
+            .map(|(kind, _)| kind.label())
+            .collect()
+    }
+
+    #[test]
+    fn disambiguates_from_context() {
+        let tagger = trained(MOVES, 10);
+        assert_eq!(labels(&tagger, &["We", "move"]), ["PRON", "VERB"]);
+        assert_eq!(labels(&tagger, &["the", "move"]), ["DET", "NOUN"]);
+    }
+
+    #[test]
+    fn tags_held_out_sentences() {
+        // The slice is small, so the bar is well above tagging every word
+        // as a noun rather than near perfect.
+        let sentences = parse_tagged(TAGGED).unwrap();
+        let (training, held_out) = sentences.split_at(sentences.len() - 6);
+        let mut trainer = PerceptronTrainer::new(8);
+        trainer.train(training);
+        let tagger = trainer.finish();
+        let accuracy = tagger.accuracy(held_out);
+        let baseline = PerceptronTagger::new().accuracy(held_out);
+        assert!(accuracy > 0.7);
+        assert!(accuracy > baseline + 0.3);
+    }
+
+    #[test]
+    fn reports_confidence() {
+        let tagger = trained(MOVES, 10);
+        let tokens = crate::tokenizer::tokenize("I move. A move.");
+        let predictions = tagger.tag(&tokens);
+        let stop = predictions[3].as_ref().unwrap();
+        assert!(matches!(
+            stop.part_of_speech(),
+            PartOfSpeech::Punctuation(_)
+        ));
+        assert_eq!(stop.confidence(), 1.0);
+        assert!(predictions[1].is_none());
+        let move_verb = predictions[2].as_ref().unwrap();
+        assert!(matches!(move_verb.part_of_speech(), PartOfSpeech::Verb(_)));
+        assert!(move_verb.confidence() > 0.5 && move_verb.confidence() <= 1.0);
+    }
+
+    #[test]
+    fn round_trips_through_the_model_format() {
+        let tagger = trained(TAGGED, 3);
+        let reloaded = PerceptronTagger::parse(&tagger.to_string()).unwrap();
+        assert_eq!(reloaded, tagger);
+        assert!(PerceptronTagger::parse("weight\tbias\tNOPE\t1\n").is_err());
+    }
+
+    #[test]
+    fn runs_as_a_pipeline_stage() {
+        let mut processor = Processor::new();
+        processor.add_stage(trained(MOVES, 10));
+        let preprocessor =
+            Preprocessor::from_text("They move the table.", PreprocessorOptions::default())
+                .unwrap();
+        let document = processor.process(&preprocessor).unwrap();
+        let moves = document.token(2).unwrap();
+        assert!(matches!(
+            moves.part_of_speech(),
+            Some(PartOfSpeech::Verb(_))
+        ));
+        assert!(moves.annotation().confidence().is_some());
+    }
+}
@@ -0,0 +1,632 @@
+pub mod contractions;
+
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use crate::error::{Error, Result};
+use contractions::{is_apostrophe, ContractionTable};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    Word,
+    Punctuation,
+    Number,
+    Whitespace,
+}
+
+impl TokenKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TokenKind::Word => "word",
+            TokenKind::Punctuation => "punctuation",
+            TokenKind::Number => "number",
+            TokenKind::Whitespace => "whitespace",
+        }
+    }
+}
+
+/// A slice of the source text together with where it was found.
+///
+/// `start` and `end` are byte offsets into the original text, `line` and
+/// `column` are 1-based, with the column counted in characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    text: String,
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Token {
+    pub fn new(text: &str, kind: TokenKind, start: usize, line: usize, column: usize) -> Token {
+        Token {
+            text: text.to_string(),
+            kind,
+            start,
+            end: start + text.len(),
+            line,
+            column,
+        }
+    }
+
+    /// A copy of this token carrying `text` but keeping the original
+    /// position, so that `text` no longer has to match the source slice.
+    pub fn with_text(&self, text: &str) -> Token {
+        Token {
+            text: text.to_string(),
+            ..self.clone()
+        }
+    }
+
+    /// Moves a token taken from a slice of a larger text to its place in
+    /// that text, given where the slice started.
+    pub(crate) fn offset_by(&mut self, offset: usize, line: usize, column: usize) {
+        if self.line == 1 {
+            self.column += column - 1;
+        }
+        self.line += line - 1;
+        self.start += offset;
+        self.end += offset;
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn kind(&self) -> TokenKind {
+        self.kind
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    pub fn span(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn is_word(&self) -> bool {
+        self.kind == TokenKind::Word
+    }
+
+    pub fn is_whitespace(&self) -> bool {
+        self.kind == TokenKind::Whitespace
+    }
+}
+
+/// Tracks the line and column of a byte offset as the text is walked.
+struct Cursor<'a> {
+    text: &'a str,
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(text: &'a str) -> Cursor<'a> {
+        Cursor {
+            text,
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn emit(&mut self, tokens: &mut VecDeque<Token>, end: usize, kind: TokenKind) {
+        if end <= self.offset {
+            return;
+        }
+        let text = &self.text[self.offset..end];
+        tokens.push_back(Token::new(text, kind, self.offset, self.line, self.column));
+
+        for c in text.chars() {
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
+        }
+        self.offset = end;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenizerMode {
+    /// Apostrophes at the edges of a word are split off like any other
+    /// punctuation.
+    Plain,
+    /// Elided words such as `'tis`, `o'` and `parents'` keep their
+    /// apostrophes.
+    Contractions,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tokenizer {
+    mode: TokenizerMode,
+    contractions: ContractionTable,
+}
+
+impl Tokenizer {
+    pub fn new(mode: TokenizerMode) -> Tokenizer {
+        Tokenizer::with_contractions(mode, ContractionTable::default())
+    }
+
+    pub fn with_contractions(mode: TokenizerMode, contractions: ContractionTable) -> Tokenizer {
+        Tokenizer { mode, contractions }
+    }
+
+    pub fn mode(&self) -> TokenizerMode {
+        self.mode
+    }
+
+    pub fn contractions(&self) -> &ContractionTable {
+        &self.contractions
+    }
+
+    pub fn tokenize(&self, text: &str) -> VecDeque<Token> {
+        match self.mode {
+            TokenizerMode::Plain => tokenize_with(text, None),
+            TokenizerMode::Contractions => tokenize_with(text, Some(&self.contractions)),
+        }
+    }
+
+    /// Like `tokenize`, but refuses text containing control characters
+    /// other than whitespace, which usually means the input is binary.
+    pub fn try_tokenize(&self, text: &str) -> Result<VecDeque<Token>> {
+        let control = text
+            .char_indices()
+            .find(|&(_, c)| c.is_control() && !c.is_whitespace());
+        match control {
+            Some((offset, c)) => Err(Error::Tokenization {
+                offset,
+                message: format!("unexpected control character {:?}", c),
+            }),
+            None => Ok(self.tokenize(text)),
+        }
+    }
+
+    /// Replaces every word token that has an entry in the contraction table
+    /// with one token per word of its expansion, each spanning the
+    /// original contraction.
+    pub fn expand(&self, tokens: &VecDeque<Token>) -> VecDeque<Token> {
+        tokens
+            .iter()
+            .flat_map(|token| self.expand_token(token))
+            .collect()
+    }
+
+    /// The expansion of a single token; a copy of `token` if it has none.
+    pub fn expand_token(&self, token: &Token) -> Vec<Token> {
+        let expansion = match token.is_word() {
+            true => self.contractions.expand(token.text()),
+            false => None,
+        };
+        match expansion {
+            Some(expansion) => expansion
+                .split_whitespace()
+                .map(|word| token.with_text(word))
+                .collect(),
+            None => vec![token.clone()],
+        }
+    }
+}
+
+impl Default for Tokenizer {
+    fn default() -> Tokenizer {
+        Tokenizer::new(TokenizerMode::Contractions)
+    }
+}
+
+pub fn tokenize(text: &str) -> VecDeque<Token> {
+    tokenize_with(text, None)
+}
+
+fn tokenize_with(text: &str, contractions: Option<&ContractionTable>) -> VecDeque<Token> {
+    let mut tokens = VecDeque::new();
+    let mut cursor = Cursor::new(text);
+
+    for (start, end, is_whitespace) in runs(text) {
+        if is_whitespace {
+            cursor.emit(&mut tokens, end, TokenKind::Whitespace);
+            continue;
+        }
+
+        for (piece_end, kind) in split_chunk(&text[start..end], contractions) {
+            cursor.emit(&mut tokens, start + piece_end, kind);
+        }
+    }
+    tokens
+}
+
+/// Splits a whitespace-free chunk into `(end, kind)` pieces, with `end`
+/// relative to the start of the chunk.
+///
+/// Dashes and ellipses break the chunk apart wherever they occur, so that
+/// `men.--Gregory` yields `men`, `.`, `--`, `Gregory`. Every other
+/// punctuation character is only split off the edges of a piece, which
+/// keeps `star-crossed` and `runn'st` whole.
+fn split_chunk(chunk: &str, contractions: Option<&ContractionTable>) -> Vec<(usize, TokenKind)> {
+    let mut pieces = Vec::new();
+    let mut piece_start = 0;
+    let mut index = 0;
+
+    while index < chunk.len() {
+        let rest = &chunk[index..];
+        let break_len = dash_len(rest).or_else(|| ellipsis_len(rest));
+        match break_len {
+            Some(len) => {
+                split_piece(chunk, piece_start..index, contractions, &mut pieces);
+                pieces.push((index + len, TokenKind::Punctuation));
+                index += len;
+                piece_start = index;
+            }
+            None => index += rest.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    split_piece(chunk, piece_start..chunk.len(), contractions, &mut pieces);
+    pieces
+}
+
+/// Peels the leading and trailing punctuation runs off `chunk[range]`,
+/// pushing one piece per punctuation mark and one for the core.
+///
+/// With a contraction table, an apostrophe directly next to the core stays
+/// attached when the result is a known elision, or, at the end of a word
+/// ending in `s`, a plural possessive.
+fn split_piece(
+    chunk: &str,
+    range: Range<usize>,
+    contractions: Option<&ContractionTable>,
+    pieces: &mut Vec<(usize, TokenKind)>,
+) {
+    let (start, end) = (range.start, range.end);
+    let piece = &chunk[range];
+    let mut core_start = piece
+        .char_indices()
+        .find(|&(_, c)| c.is_alphanumeric())
+        .map_or(piece.len(), |(index, _)| index);
+    let mut core_end = piece
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| c.is_alphanumeric())
+        .map_or(core_start, |(index, c)| index + c.len_utf8());
+
+    if let (Some(table), true) = (contractions, core_end > core_start) {
+        let leading = piece[..core_start]
+            .chars()
+            .next_back()
+            .filter(|&c| is_apostrophe(c));
+        if let Some(apostrophe) = leading {
+            let candidate = &piece[core_start - apostrophe.len_utf8()..core_end];
+            if table.contains_word(candidate) {
+                core_start -= apostrophe.len_utf8();
+            }
+        }
+
+        let trailing = piece[core_end..]
+            .chars()
+            .next()
+            .filter(|&c| is_apostrophe(c));
+        if let Some(apostrophe) = trailing {
+            let candidate = &piece[core_start..core_end + apostrophe.len_utf8()];
+            let possessive = leading.is_none() && piece[..core_end].ends_with(['s', 'S']);
+            if table.contains_word(candidate) || possessive {
+                core_end += apostrophe.len_utf8();
+            }
+        }
+    }
+
+    push_punctuation(chunk, start, start + core_start, pieces);
+    if core_end > core_start {
+        pieces.push((start + core_end, classify(&piece[core_start..core_end])));
+    }
+    push_punctuation(chunk, start + core_end, end, pieces);
+}
+
+fn push_punctuation(chunk: &str, start: usize, end: usize, pieces: &mut Vec<(usize, TokenKind)>) {
+    let mut index = start;
+    while index < end {
+        let rest = &chunk[index..end];
+        let len = ellipsis_len(rest)
+            .or_else(|| dash_len(rest))
+            .unwrap_or_else(|| rest.chars().next().map_or(1, char::len_utf8));
+        index += len;
+        pieces.push((index, TokenKind::Punctuation));
+    }
+}
+
+/// Length of a dash at the start of `text`: an em or en dash, or a run of
+/// two or more hyphens standing in for one.
+fn dash_len(text: &str) -> Option<usize> {
+    if text.starts_with(['\u{2014}', '\u{2013}']) {
+        return Some(text.chars().next().map_or(0, char::len_utf8));
+    }
+    let hyphens = text.len() - text.trim_start_matches('-').len();
+    if hyphens >= 2 {
+        Some(hyphens)
+    } else {
+        None
+    }
+}
+
+/// Length of an ellipsis at the start of `text`: `…` or a run of two or
+/// more full stops.
+fn ellipsis_len(text: &str) -> Option<usize> {
+    if text.starts_with('\u{2026}') {
+        return Some('\u{2026}'.len_utf8());
+    }
+    let dots = text.len() - text.trim_start_matches('.').len();
+    if dots >= 2 {
+        Some(dots)
+    } else {
+        None
+    }
+}
+
+fn classify(text: &str) -> TokenKind {
+    let is_number = text.starts_with(|c: char| c.is_ascii_digit())
+        && text
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == '.' || c == ',');
+    if is_number {
+        TokenKind::Number
+    } else {
+        TokenKind::Word
+    }
+}
+
+/// Splits `text` into alternating runs of whitespace and non-whitespace,
+/// returned as `(start, end, is_whitespace)` byte ranges.
+fn runs(text: &str) -> Vec<(usize, usize, bool)> {
+    let mut runs: Vec<(usize, usize, bool)> = Vec::new();
+
+    for (index, c) in text.char_indices() {
+        let is_whitespace = c.is_whitespace();
+        let end = index + c.len_utf8();
+        match runs.last_mut() {
+            Some(run) if run.2 == is_whitespace => run.1 = end,
+            _ => runs.push((index, end, is_whitespace)),
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(text: &str) -> Vec<String> {
+        tokenize(text)
+            .iter()
+            .filter(|token| !token.is_whitespace())
+            .map(|token| token.text().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn strips_runs_of_trailing_punctuation() {
+        assert_eq!(
+            texts("(In fair Verona, where we lay our scene),"),
+            ["(", "In", "fair", "Verona", ",", "where", "we", "lay", "our", "scene", ")", ","]
+        );
+    }
+
+    #[test]
+    fn strips_quotes_around_exclamations() {
+        assert_eq!(texts("say \"Ay\"?"), ["say", "\"", "Ay", "\"", "?"]);
+        assert_eq!(texts("\"Ay!\""), ["\"", "Ay", "!", "\""]);
+        assert_eq!(
+            texts("Wilt thou not, Jule?\" And,"),
+            ["Wilt", "thou", "not", ",", "Jule", "?", "\"", "And", ","]
+        );
+    }
+
+    #[test]
+    fn splits_bracketed_stage_directions() {
+        assert_eq!(texts("[Chorus exits.]"), ["[", "Chorus", "exits", ".", "]"]);
+        assert_eq!(
+            texts("GREGORY, [aside to Sampson]  Say \"better\"; here"),
+            [
+                "GREGORY", ",", "[", "aside", "to", "Sampson", "]", "Say", "\"", "better", "\"",
+                ";", "here"
+            ]
+        );
+    }
+
+    #[test]
+    fn splits_on_double_hyphen_dashes() {
+        assert_eq!(
+            texts("SAMPSON  Draw if you be men.--Gregory, remember"),
+            ["SAMPSON", "Draw", "if", "you", "be", "men", ".", "--", "Gregory", ",", "remember"]
+        );
+        assert_eq!(texts("ROMEO  Out--"), ["ROMEO", "Out", "--"]);
+        assert_eq!(
+            texts("Will they not hear?--What ho!"),
+            ["Will", "they", "not", "hear", "?", "--", "What", "ho", "!"]
+        );
+    }
+
+    #[test]
+    fn keeps_hyphenated_words_whole() {
+        assert_eq!(
+            texts("A pair of star-crossed lovers take their life;"),
+            [
+                "A",
+                "pair",
+                "of",
+                "star-crossed",
+                "lovers",
+                "take",
+                "their",
+                "life",
+                ";"
+            ]
+        );
+    }
+
+    #[test]
+    fn handles_curly_quotes_em_dashes_and_ellipses() {
+        assert_eq!(
+            texts("\u{201c}Ay!\u{201d}"),
+            ["\u{201c}", "Ay", "!", "\u{201d}"]
+        );
+        assert_eq!(texts("men\u{2014}Gregory"), ["men", "\u{2014}", "Gregory"]);
+        assert_eq!(texts("so\u{2026}"), ["so", "\u{2026}"]);
+        assert_eq!(texts("and yet...no."), ["and", "yet", "...", "no", "."]);
+        assert_eq!(texts("away...\""), ["away", "...", "\""]);
+    }
+
+    #[test]
+    fn is_char_boundary_safe() {
+        assert_eq!(texts("caf\u{e9}\u{2019}"), ["caf\u{e9}", "\u{2019}"]);
+        assert_eq!(texts("\u{2018}\u{2019}"), ["\u{2018}", "\u{2019}"]);
+        assert_eq!(texts("\u{e9}"), ["\u{e9}"]);
+    }
+
+    #[test]
+    fn classifies_token_kinds() {
+        let kinds: Vec<TokenKind> = tokenize("ACT 1,").iter().map(Token::kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                TokenKind::Word,
+                TokenKind::Whitespace,
+                TokenKind::Number,
+                TokenKind::Punctuation
+            ]
+        );
+    }
+
+    #[test]
+    fn records_offsets_lines_and_columns() {
+        let text = "Ay, while you live, draw your neck out of\ncollar.";
+        let tokens = tokenize(text);
+        for token in &tokens {
+            assert_eq!(&text[token.span()], token.text());
+        }
+
+        let collar = tokens
+            .iter()
+            .find(|token| token.text() == "collar")
+            .unwrap();
+        assert_eq!((collar.line(), collar.column()), (2, 1));
+        assert_eq!(collar.start(), text.find("collar").unwrap());
+    }
+
+    fn contraction_texts(text: &str) -> Vec<String> {
+        Tokenizer::default()
+            .tokenize(text)
+            .iter()
+            .filter(|token| !token.is_whitespace())
+            .map(|token| token.text().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn plain_mode_splits_edge_apostrophes() {
+        assert_eq!(texts("'Tis true"), ["'", "Tis", "true"]);
+        assert_eq!(
+            texts("our parents' strife"),
+            ["our", "parents", "'", "strife"]
+        );
+    }
+
+    #[test]
+    fn keeps_elided_words_intact() {
+        assert_eq!(
+            contraction_texts("SAMPSON  'Tis true, and we'll draw."),
+            ["SAMPSON", "'Tis", "true", ",", "and", "we'll", "draw", "."]
+        );
+        assert_eq!(
+            contraction_texts("thou runn'st away o'er the wall"),
+            ["thou", "runn'st", "away", "o'er", "the", "wall"]
+        );
+        assert_eq!(
+            contraction_texts("the top o' th' morning"),
+            ["the", "top", "o'", "th'", "morning"]
+        );
+        assert_eq!(
+            contraction_texts("their parents' strife."),
+            ["their", "parents'", "strife", "."]
+        );
+        assert_eq!(contraction_texts("\u{2019}Twas so"), ["\u{2019}Twas", "so"]);
+    }
+
+    #[test]
+    fn still_splits_single_quotations() {
+        assert_eq!(contraction_texts("'Away'"), ["'", "Away", "'"]);
+    }
+
+    #[test]
+    fn expands_contractions() {
+        let tokenizer = Tokenizer::default();
+        let tokens = tokenizer.tokenize("'Tis true, we'll ne'er go; thou runn'st.");
+        let expanded: Vec<String> = tokenizer
+            .expand(&tokens)
+            .iter()
+            .filter(|token| token.is_word())
+            .map(|token| token.text().to_string())
+            .collect();
+        assert_eq!(
+            expanded,
+            ["It", "is", "true", "we", "will", "never", "go", "thou", "runnest"]
+        );
+    }
+
+    #[test]
+    fn expansions_keep_the_contraction_span() {
+        let tokenizer = Tokenizer::default();
+        let tokens = tokenizer.tokenize("so o'er");
+        let expanded = tokenizer.expand(&tokens);
+        let over = expanded
+            .iter()
+            .find(|token| token.text() == "over")
+            .unwrap();
+        assert_eq!(over.span(), 3..7);
+    }
+
+    #[test]
+    fn expansion_table_is_configurable() {
+        let mut table = ContractionTable::new();
+        table.insert_word("'tis", "it be");
+        let tokenizer = Tokenizer::with_contractions(TokenizerMode::Contractions, table);
+        let tokens = tokenizer.tokenize("'tis o'er");
+        let expanded: Vec<String> = tokenizer
+            .expand(&tokens)
+            .iter()
+            .filter(|token| token.is_word())
+            .map(|token| token.text().to_string())
+            .collect();
+        assert_eq!(expanded, ["it", "be", "o'er"]);
+    }
+
+    #[test]
+    fn rejects_binary_input() {
+        let result = Tokenizer::default().try_tokenize("Romeo\u{0}\u{1}");
+        assert!(matches!(result, Err(Error::Tokenization { offset: 5, .. })));
+        assert!(Tokenizer::default().try_tokenize("Romeo\r\n\t").is_ok());
+    }
+
+    #[test]
+    fn covers_the_whole_corpus() {
+        let text = include_str!("../romeo_and_juliet.txt");
+        let tokens = tokenize(text);
+        let rebuilt: String = tokens.iter().map(Token::text).collect();
+        assert_eq!(rebuilt, text);
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+const WORDS: [(&str, &str); 48] = [
+    ("'tis", "it is"),
+    ("'twas", "it was"),
+    ("'twere", "it were"),
+    ("'twill", "it will"),
+    ("'twould", "it would"),
+    ("'t", "it"),
+    ("'em", "them"),
+    ("'gainst", "against"),
+    ("'twixt", "betwixt"),
+    ("'tween", "between"),
+    ("'scape", "escape"),
+    ("'ware", "aware"),
+    ("'mongst", "amongst"),
+    ("o'er", "over"),
+    ("ne'er", "never"),
+    ("e'er", "ever"),
+    ("e'en", "even"),
+    ("whate'er", "whatever"),
+    ("whoe'er", "whoever"),
+    ("where'er", "wherever"),
+    ("o'", "of"),
+    ("th'", "the"),
+    ("i'", "in"),
+    ("i'll", "I will"),
+    ("i'm", "I am"),
+    ("i've", "I have"),
+    ("i'd", "I would"),
+    ("you'd", "you would"),
+    ("he'd", "he would"),
+    ("she'd", "she would"),
+    ("we'd", "we would"),
+    ("they'd", "they would"),
+    ("let's", "let us"),
+    ("won't", "will not"),
+    ("can't", "cannot"),
+    ("shan't", "shall not"),
+    ("ain't", "am not"),
+    ("he's", "he is"),
+    ("she's", "she is"),
+    ("it's", "it is"),
+    ("that's", "that is"),
+    ("what's", "what is"),
+    ("here's", "here is"),
+    ("there's", "there is"),
+    ("where's", "where is"),
+    ("who's", "who is"),
+    ("how's", "how is"),
+    ("stol'n", "stolen"),
+];
+
+const SUFFIXES: [(&str, &str); 9] = [
+    ("n't", " not"),
+    ("'ll", " will"),
+    ("'re", " are"),
+    ("'ve", " have"),
+    ("'m", " am"),
+    ("'t", " it"),
+    ("'st", "est"),
+    ("'d", "ed"),
+    ("'n", "en"),
+];
+
+/// Maps contracted and elided forms to their expansions.
+///
+/// Whole words such as `'tis` are looked up first; failing that the longest
+/// matching suffix rule is applied, so `we'll` becomes `we will` and
+/// `runn'st` becomes `runnest`. Keys are lowercase and use a straight
+/// apostrophe; lookups fold curly apostrophes and case before matching.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractionTable {
+    words: HashMap<String, String>,
+    suffixes: Vec<(String, String)>,
+}
+
+impl ContractionTable {
+    pub fn new() -> ContractionTable {
+        ContractionTable {
+            words: HashMap::new(),
+            suffixes: Vec::new(),
+        }
+    }
+
+    pub fn insert_word(&mut self, contraction: &str, expansion: &str) {
+        self.words
+            .insert(normalize(contraction), expansion.to_string());
+    }
+
+    pub fn insert_suffix(&mut self, suffix: &str, replacement: &str) {
+        let suffix = normalize(suffix);
+        self.suffixes.retain(|(existing, _)| *existing != suffix);
+        self.suffixes.push((suffix, replacement.to_string()));
+        self.suffixes
+            .sort_by_key(|(suffix, _)| std::cmp::Reverse(suffix.len()));
+    }
+
+    pub fn remove_word(&mut self, contraction: &str) -> Option<String> {
+        self.words.remove(&normalize(contraction))
+    }
+
+    /// Whether `word` is listed as a whole-word contraction.
+    pub fn contains_word(&self, word: &str) -> bool {
+        self.words.contains_key(&normalize(word))
+    }
+
+    /// Expands `word`, keeping a leading capital, or returns `None` if no
+    /// entry or suffix rule applies.
+    pub fn expand(&self, word: &str) -> Option<String> {
+        let key = normalize(word);
+        let expansion = match self.words.get(&key) {
+            Some(expansion) => expansion.clone(),
+            None => {
+                let (suffix, replacement) = self.suffixes.iter().find(|(suffix, _)| {
+                    key.len() > suffix.len() && key.ends_with(suffix.as_str())
+                })?;
+                let stem_len = word.chars().count() - suffix.chars().count();
+                let stem: String = word.chars().take(stem_len).collect();
+                return Some(format!("{}{}", stem, replacement));
+            }
+        };
+
+        let capitalized = word
+            .chars()
+            .find(|c| c.is_alphabetic())
+            .is_some_and(char::is_uppercase);
+        if capitalized {
+            Some(capitalize(&expansion))
+        } else {
+            Some(expansion)
+        }
+    }
+}
+
+impl Default for ContractionTable {
+    fn default() -> ContractionTable {
+        let mut table = ContractionTable::new();
+        for (contraction, expansion) in WORDS {
+            table.insert_word(contraction, expansion);
+        }
+        for (suffix, replacement) in SUFFIXES {
+            table.insert_suffix(suffix, replacement);
+        }
+        table
+    }
+}
+
+pub fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '\u{2019}'
+}
+
+fn normalize(word: &str) -> String {
+    word.chars()
+        .map(|c| if is_apostrophe(c) { '\'' } else { c })
+        .collect::<String>()
+        .to_lowercase()
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,335 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
+
+use crate::tokenizer::contractions::is_apostrophe;
+use crate::tokenizer::Token;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VocabularyEntry {
+    id: usize,
+    word: String,
+    frequency: u64,
+    first_offset: usize,
+    document_frequency: u64,
+    last_document: u64,
+}
+
+impl VocabularyEntry {
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    pub fn frequency(&self) -> u64 {
+        self.frequency
+    }
+
+    /// Byte offset of the first occurrence, within the first document the
+    /// word appeared in.
+    pub fn first_offset(&self) -> usize {
+        self.first_offset
+    }
+
+    pub fn document_frequency(&self) -> u64 {
+        self.document_frequency
+    }
+}
+
+/// Word types with their counts, keyed by lowercase form.
+///
+/// Ids are assigned in order of first occurrence. With a type limit set,
+/// the least frequent types are evicted whenever a new type would exceed
+/// the limit, so counts become approximate but memory stays bounded; an
+/// evicted word that comes back is counted afresh under a new id.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Vocabulary {
+    entries: Vec<VocabularyEntry>,
+    positions: HashMap<String, usize>,
+    next_id: usize,
+    token_count: u64,
+    document_count: u64,
+    limit: Option<usize>,
+}
+
+impl Vocabulary {
+    pub fn new() -> Vocabulary {
+        Vocabulary::default()
+    }
+
+    /// A vocabulary that never holds more than `limit` types.
+    pub fn with_limit(limit: usize) -> Vocabulary {
+        Vocabulary {
+            limit: Some(limit.max(1)),
+            ..Vocabulary::default()
+        }
+    }
+
+    pub fn from_tokens(tokens: &VecDeque<Token>) -> Vocabulary {
+        let mut vocabulary = Vocabulary::new();
+        vocabulary.add_document(tokens);
+        vocabulary
+    }
+
+    /// Counts the word tokens of one document.
+    pub fn add_document(&mut self, tokens: &VecDeque<Token>) {
+        self.begin_document();
+        for token in tokens {
+            self.add_token(token);
+        }
+    }
+
+    /// Starts a new document; later tokens count towards the document
+    /// frequency of their words once per document.
+    pub fn begin_document(&mut self) {
+        self.document_count += 1;
+    }
+
+    /// Counts `token` if it is a word, returning the id of its entry.
+    pub fn add_token(&mut self, token: &Token) -> Option<usize> {
+        self.add_token_as(token, token.text())
+    }
+
+    /// Counts `token` if it is a word under `word` instead of its own
+    /// text, as when counting lemmas.
+    pub fn add_token_as(&mut self, token: &Token, word: &str) -> Option<usize> {
+        if !token.is_word() {
+            return None;
+        }
+        let word = normalize(word)?;
+        self.token_count += 1;
+        let document = self.document_count;
+
+        if let Some(&position) = self.positions.get(&word) {
+            let entry = &mut self.entries[position];
+            entry.frequency += 1;
+            if entry.last_document != document {
+                entry.last_document = document;
+                entry.document_frequency += 1;
+            }
+            return Some(entry.id);
+        }
+
+        if self.limit.is_some_and(|limit| self.entries.len() >= limit) {
+            self.evict();
+        }
+        let id = self.next_id;
+        self.next_id += 1;
+        self.positions.insert(word.clone(), self.entries.len());
+        self.entries.push(VocabularyEntry {
+            id,
+            word,
+            frequency: 1,
+            first_offset: token.start(),
+            document_frequency: 1,
+            last_document: document,
+        });
+        Some(id)
+    }
+
+    /// Drops the least frequent quarter of the types, most recent first
+    /// among equals.
+    fn evict(&mut self) {
+        let keep = self
+            .limit
+            .map_or(self.entries.len(), |limit| limit - limit / 4);
+        let mut ranked: Vec<(u64, Reverse<usize>)> = self
+            .entries
+            .iter()
+            .map(|entry| (entry.frequency, Reverse(entry.id)))
+            .collect();
+        ranked.sort_unstable();
+        let evicted = ranked.len().saturating_sub(keep).max(1);
+        let cutoff = ranked[evicted - 1];
+
+        self.entries
+            .retain(|entry| (entry.frequency, Reverse(entry.id)) > cutoff);
+        self.positions = self
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(position, entry)| (entry.word.clone(), position))
+            .collect();
+    }
+
+    pub fn get(&self, word: &str) -> Option<&VocabularyEntry> {
+        let position = *self.positions.get(&normalize(word)?)?;
+        self.entries.get(position)
+    }
+
+    pub fn id(&self, word: &str) -> Option<usize> {
+        self.get(word).map(VocabularyEntry::id)
+    }
+
+    pub fn word(&self, id: usize) -> Option<&str> {
+        let position = self
+            .entries
+            .binary_search_by_key(&id, |entry| entry.id)
+            .ok()?;
+        Some(&self.entries[position].word)
+    }
+
+    pub fn frequency(&self, word: &str) -> u64 {
+        self.get(word).map_or(0, VocabularyEntry::frequency)
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.get(word).is_some()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn token_count(&self) -> u64 {
+        self.token_count
+    }
+
+    pub fn document_count(&self) -> u64 {
+        self.document_count
+    }
+
+    /// Entries in order of first occurrence.
+    pub fn iter(&self) -> impl Iterator<Item = &VocabularyEntry> {
+        self.entries.iter()
+    }
+
+    /// The `n` most frequent entries, ties broken by first occurrence.
+    pub fn top_n(&self, n: usize) -> Vec<&VocabularyEntry> {
+        let mut entries: Vec<&VocabularyEntry> = self.entries.iter().collect();
+        entries.sort_by(|a, b| b.frequency.cmp(&a.frequency).then(a.id.cmp(&b.id)));
+        entries.truncate(n);
+        entries
+    }
+
+    /// Entries that occur exactly once.
+    pub fn hapax_legomena(&self) -> Vec<&VocabularyEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.frequency == 1)
+            .collect()
+    }
+
+    /// Number of types over number of tokens, or zero for an empty
+    /// vocabulary.
+    pub fn type_token_ratio(&self) -> f64 {
+        if self.token_count == 0 {
+            0.0
+        } else {
+            self.entries.len() as f64 / self.token_count as f64
+        }
+    }
+}
+
+/// Lowercases `word` and strips any non-letters from its edges, keeping
+/// apostrophes so that elisions like `'tis` and `o'` stay distinct.
+pub fn normalize(word: &str) -> Option<String> {
+    let stripped = word.trim_matches(|c: char| !c.is_alphabetic() && !is_apostrophe(c));
+    if !stripped.chars().any(char::is_alphabetic) {
+        return None;
+    }
+    let normalized = stripped
+        .chars()
+        .map(|c| if is_apostrophe(c) { '\'' } else { c })
+        .collect::<String>();
+    Some(normalized.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+
+    fn vocabulary(text: &str) -> Vocabulary {
+        Vocabulary::from_tokens(&Tokenizer::default().tokenize(text))
+    }
+
+    #[test]
+    fn counts_words_case_insensitively() {
+        let vocabulary = vocabulary("The wall, the WALL; to the wall!");
+        assert_eq!(vocabulary.len(), 3);
+        assert_eq!(vocabulary.token_count(), 7);
+        assert_eq!(vocabulary.frequency("the"), 3);
+        assert_eq!(vocabulary.frequency("Wall"), 3);
+        assert_eq!(vocabulary.frequency("moon"), 0);
+    }
+
+    #[test]
+    fn records_ids_and_first_offsets() {
+        let vocabulary = vocabulary("Draw, draw your neck");
+        let draw = vocabulary.get("draw").unwrap();
+        assert_eq!((draw.id(), draw.first_offset()), (0, 0));
+        assert_eq!(vocabulary.id("neck"), Some(2));
+        assert_eq!(vocabulary.word(1), Some("your"));
+    }
+
+    #[test]
+    fn tracks_document_frequency() {
+        let tokenizer = Tokenizer::default();
+        let mut vocabulary = Vocabulary::new();
+        vocabulary.add_document(&tokenizer.tokenize("a rose a rose"));
+        vocabulary.add_document(&tokenizer.tokenize("a thorn"));
+        assert_eq!(vocabulary.document_count(), 2);
+        assert_eq!(vocabulary.get("a").unwrap().document_frequency(), 2);
+        assert_eq!(vocabulary.get("rose").unwrap().document_frequency(), 1);
+        assert_eq!(vocabulary.frequency("rose"), 2);
+    }
+
+    #[test]
+    fn answers_frequency_queries() {
+        let vocabulary = vocabulary("to be or not to be");
+        let top: Vec<&str> = vocabulary
+            .top_n(2)
+            .iter()
+            .map(|entry| entry.word())
+            .collect();
+        assert_eq!(top, ["to", "be"]);
+
+        let hapaxes: Vec<&str> = vocabulary
+            .hapax_legomena()
+            .iter()
+            .map(|entry| entry.word())
+            .collect();
+        assert_eq!(hapaxes, ["or", "not"]);
+        assert!((vocabulary.type_token_ratio() - 4.0 / 6.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stays_within_its_limit() {
+        let tokens =
+            Tokenizer::default().tokenize(&"the rose the thorn the stem a b c d e f ".repeat(3));
+        let mut vocabulary = Vocabulary::with_limit(4);
+        vocabulary.add_document(&tokens);
+
+        assert!(vocabulary.len() <= 4);
+        assert_eq!(vocabulary.frequency("the"), 9);
+        assert_eq!(vocabulary.token_count(), 36);
+        let the = vocabulary.get("the").unwrap();
+        assert_eq!(vocabulary.word(the.id()), Some("the"));
+    }
+
+    #[test]
+    fn returns_ids_that_resolve_after_eviction() {
+        let mut vocabulary = Vocabulary::with_limit(2);
+        for token in &Tokenizer::default().tokenize("the the rose thorn stem") {
+            if let Some(id) = vocabulary.add_token(token) {
+                assert_eq!(vocabulary.word(id), Some(token.text()));
+            }
+        }
+        assert!(vocabulary.len() <= 2);
+        assert!(vocabulary.contains("stem"));
+    }
+
+    #[test]
+    fn keeps_elisions_distinct() {
+        let vocabulary = vocabulary("'Tis \u{2019}tis o'er");
+        assert_eq!(vocabulary.frequency("'tis"), 2);
+        assert!(vocabulary.contains("o'er"));
+    }
+}